@@ -0,0 +1,57 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
+
+// ----------------------------------------------------------------
+// NOTE
+// ----------------------------------------------------------------
+
+// In ‘Absurdle‘ the programme does not fix a solution in advance.
+// Instead, after each guess it answers with the feedback pattern,
+// which keeps as many of the remaining words as possible.
+
+// ----------------------------------------------------------------
+// Methods
+// ----------------------------------------------------------------
+
+/// chooses the feedback to a guess, which keeps the largest set of remaining words.
+/// In case of ties the pattern revealing the least (i.e. the lowest pattern) is chosen.
+///
+/// ## Returns ##
+///
+/// `(pattern, words_remaining)`, i.e. the chosen feedback and the words compatible with it.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::absurdle::choose_feedback;
+/// use wordle::app::patterns::FeedbackMatrix;
+/// use wordle::app::patterns::pattern_to_feedback;
+/// let words = vec![String::from("crane"), String::from("crake"), String::from("pilot")];
+/// let matrix = FeedbackMatrix::new(&words, &words);
+/// let (pattern, words_remaining) = choose_feedback(&matrix, &String::from("pilot"), &words);
+/// assert_eq!(pattern_to_feedback(pattern, 5), "xxxxx");
+/// assert_eq!(words_remaining, vec![String::from("crane"), String::from("crake")]);
+/// // with two words left, the player cannot win by guessing one of them:
+/// let (_, words_remaining) = choose_feedback(&matrix, &String::from("crane"), &words_remaining);
+/// assert_eq!(words_remaining, vec![String::from("crake")]);
+/// ```
+pub fn choose_feedback(matrix: &FeedbackMatrix, guess: &String, words: &Vec<String>) -> (Pattern, Vec<String>) {
+    let mut buckets: BTreeMap<Pattern, Vec<String>> = BTreeMap::new();
+    for word in words.iter() {
+        buckets.entry(matrix.get(guess, word)).or_insert(vec![]).push(word.clone());
+    }
+    let mut choice: (Pattern, Vec<String>) = (0, vec![]);
+    // NOTE: buckets are traversed by increasing pattern, so that ties go to the lowest pattern.
+    for (pattern, bucket) in buckets.into_iter() {
+        if bucket.len() > choice.1.len() {
+            choice = (pattern, bucket);
+        }
+    }
+    return choice;
+}
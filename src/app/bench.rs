@@ -0,0 +1,190 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
+use crate::app::solver;
+use crate::app::states::WordlState;
+use crate::app::tactics::selection::Tactic;
+use crate::setup::config::ConfigParams;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+/// Games not solved after this many guesses are aborted.
+static MAX_TURNS: usize = 100;
+
+// ----------------------------------------------------------------
+// Structure BenchResult
+// ----------------------------------------------------------------
+
+/// Summary of the solver playing against every word in a list of solutions.
+pub struct BenchResult {
+    pub tactic: Tactic,
+    pub start: Option<String>,
+    /// number of guesses needed per solution (`None` if aborted)
+    pub guesses: Vec<Option<usize>>,
+    pub max_guesses: usize,
+    pub duration: Duration,
+}
+
+// ----------------------------------------------------------------
+// Implementation BenchResult
+// ----------------------------------------------------------------
+
+impl BenchResult {
+    pub fn len(self: &Self) -> usize {
+        return self.guesses.len();
+    }
+
+    /// average number of guesses of solved games
+    pub fn mean(self: &Self) -> f64 {
+        let solved = self.guesses.iter().filter_map(|&n| n).collect::<Vec<usize>>();
+        if solved.len() == 0 {
+            return 0.;
+        }
+        return (solved.iter().sum::<usize>() as f64)/(solved.len() as f64);
+    }
+
+    /// largest number of guesses needed (`None` if some game was aborted)
+    pub fn worst(self: &Self) -> Option<usize> {
+        let mut worst = 0;
+        for n in self.guesses.iter() {
+            match n {
+                Some(n) => { worst = worst.max(*n); },
+                None => { return None; },
+            }
+        }
+        return Some(worst);
+    }
+
+    /// number of games needing more than the maximal number of guesses
+    pub fn failures(self: &Self) -> usize {
+        return self.guesses.iter()
+            .filter(|n| n.map(|n| n > self.max_guesses).unwrap_or(true))
+            .count();
+    }
+
+    /// number of games per number of guesses needed
+    pub fn distribution(self: &Self) -> BTreeMap<usize, usize> {
+        let mut distribution: BTreeMap<usize, usize> = BTreeMap::new();
+        for n in self.guesses.iter().filter_map(|&n| n) {
+            *distribution.entry(n).or_insert(0) += 1;
+        }
+        return distribution;
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods
+// ----------------------------------------------------------------
+
+/// lets the solver play against every solution, using `WordlState::from` as the oracle
+///
+/// ## Arguments ##
+///
+/// - `config` - settings, whereby `config.tactic` determines the tactic used
+/// - `matrix` - precomputed feedback patterns (contains the list of allowed guesses)
+/// - `solutions` - list of possible solutions
+/// - `targets` - list of solutions to play against
+/// - `start` - optional fixed first guess
+///
+/// ## Examples ##
+///
+/// Games of 4, 6 and 7 letters (with one more guess than letters by default):
+///
+/// ```rust
+/// use wordle::app::bench::run_benchmark;
+/// use wordle::app::patterns::FeedbackMatrix;
+/// use wordle::setup::assets::get_config;
+/// use wordle::setup::assets::get_word_lists;
+/// use wordle::setup::config::set_config;
+/// use wordle::setup::config::ConfigLayer;
+/// let words = "
+///     cart care core cure fire fore lore more pure sure wire
+///     bright flower garden marble planet silver stream summer winter
+///     balance capture diamond example fortune kingdom machine picture
+/// ".split_whitespace().map(String::from).collect::<Vec<String>>();
+/// for &(length, start) in [(4, "core"), (6, "garden"), (7, "machine")].iter() {
+///     let layers = vec![
+///         ConfigLayer { source: String::from("default"), spec: get_config().unwrap() },
+///         ConfigLayer::from_setting("--length", "size-of-wordle", &length.to_string()),
+///     ];
+///     let config = set_config(&layers, &String::from("0.0.0")).ok().unwrap();
+///     assert_eq!(config.max_guesses, length + 1);
+///     let (guesses, solutions) = get_word_lists(&words, &words, config.size_of_wordle);
+///     assert!(solutions.iter().all(|word| word.len() == length));
+///     let matrix = FeedbackMatrix::new(&guesses, &solutions);
+///     for start in [None, Some(String::from(start))].iter() {
+///         let result = run_benchmark(&config, &matrix, &solutions, &solutions, start);
+///         assert_eq!(result.len(), solutions.len());
+///         assert_eq!(result.failures(), 0);
+///     }
+/// }
+/// ```
+pub fn run_benchmark(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    solutions: &Vec<String>,
+    targets: &Vec<String>,
+    start: &Option<String>,
+) -> BenchResult {
+    let time = Instant::now();
+    // games are deterministic, so the next guess only depends upon the feedback so far:
+    let mut cache: HashMap<Vec<Pattern>, String> = HashMap::new();
+    let guesses = targets.iter()
+        .map(|solution| play_game(config, matrix, solutions, solution, start, &mut cache))
+        .collect::<Vec<Option<usize>>>();
+    return BenchResult {
+        tactic: config.tactic,
+        start: start.clone(),
+        guesses,
+        max_guesses: config.max_guesses,
+        duration: time.elapsed(),
+    };
+}
+
+/// plays a single game and returns the number of guesses needed (`None` if aborted)
+fn play_game(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    solutions: &Vec<String>,
+    solution: &String,
+    start: &Option<String>,
+    cache: &mut HashMap<Vec<Pattern>, String>,
+) -> Option<usize> {
+    let mut words_remaining = solutions.clone();
+    let mut history = Vec::<WordlState>::new();
+    let mut path = Vec::<Pattern>::new();
+    for turn in 1..=MAX_TURNS {
+        let guess = match (turn, start) {
+            (1, Some(word)) => word.clone(),
+            _ => {
+                if !cache.contains_key(&path) {
+                    let guess = if words_remaining.len() <= 1 {
+                        words_remaining.get(0).cloned()
+                    } else {
+                        solver::get_suggestions(config, matrix, &history, &words_remaining).get(0).cloned()
+                    };
+                    cache.insert(path.clone(), guess?);
+                }
+                cache.get(&path).unwrap().clone()
+            },
+        };
+        let state = WordlState::from(&guess, solution);
+        if state.is_correct() {
+            return Some(turn);
+        }
+        words_remaining = state.constrain_with_matrix(matrix, &words_remaining);
+        path.push(state.to_pattern());
+        history.push(state);
+    }
+    return None;
+}
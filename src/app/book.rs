@@ -0,0 +1,179 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate yaml_rust;
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use self::yaml_rust::Yaml;
+use self::yaml_rust::yaml::Hash;
+
+use crate::app::patterns;
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
+use crate::app::solver;
+use crate::app::states::WordlState;
+use crate::app::tactics;
+use crate::app::tactics::selection::Tactic;
+use crate::core::console;
+use crate::core::utils;
+use crate::setup::config::ConfigParams;
+
+// ----------------------------------------------------------------
+// Structure OpeningBook
+// ----------------------------------------------------------------
+
+/// The best first guess and the best second guess for each feedback to it,
+/// valid for one pair of word lists (identified by the key of the feedback matrix) and one tactic.
+pub struct OpeningBook {
+    pub key: String,
+    pub tactic: Tactic,
+    pub hard_mode: bool,
+    pub opener: String,
+    pub replies: BTreeMap<Pattern, String>,
+}
+
+// ----------------------------------------------------------------
+// Implementation OpeningBook
+// ----------------------------------------------------------------
+
+impl OpeningBook {
+    /// computes the book, ranking the opener against all solutions
+    /// (regardless of `max-length-for-best-optimisation`).
+    pub fn new(config: &ConfigParams, matrix: &FeedbackMatrix) -> Self {
+        let solutions = matrix.solutions();
+        let mut guesses = matrix.guesses().clone();
+        tactics::selection::reduce_sort_by_tactic(&config.tactic, matrix, &mut guesses, solutions);
+        let opener = guesses.get(0).cloned().unwrap_or(String::from(""));
+        let size_of_word = utils::length_of_word(&opener);
+        let mut partition: BTreeMap<Pattern, Vec<String>> = BTreeMap::new();
+        for word in solutions.iter() {
+            partition.entry(matrix.get(&opener, word)).or_insert(vec![]).push(word.clone());
+        }
+        let mut replies: BTreeMap<Pattern, String> = BTreeMap::new();
+        for (pattern, words_remaining) in partition.iter() {
+            if *pattern == patterns::pattern_correct(size_of_word) {
+                continue;
+            }
+            let history = vec![WordlState::new(&opener, &patterns::pattern_to_feedback(*pattern, size_of_word))];
+            let reply = if words_remaining.len() == 1 {
+                words_remaining.get(0).cloned()
+            } else {
+                solver::get_suggestions(config, matrix, &history, words_remaining).get(0).cloned()
+            };
+            match reply {
+                Some(word) => { replies.insert(*pattern, word); },
+                None => { },
+            }
+        }
+        return OpeningBook {
+            key: matrix.key(),
+            tactic: config.tactic,
+            hard_mode: config.hard_mode,
+            opener,
+            replies,
+        };
+    }
+
+    /// loads the book from `path_to_directory` if present and valid, otherwise computes and saves it
+    pub fn load_or_new(config: &ConfigParams, matrix: &FeedbackMatrix) -> Self {
+        let path = get_path_to_book(&config.data_directory, &matrix.key(), &config.tactic, config.hard_mode);
+        match OpeningBook::read(&path) {
+            Ok(book) => {
+                if book.is_valid_for(config, matrix) {
+                    return book;
+                }
+            },
+            Err(_) => { },
+        }
+        eprintln!("{}", console::paint("\x1b[2mComputing opening book (only needed once per word list and tactic)...\x1b[0m"));
+        let book = OpeningBook::new(config, matrix);
+        match book.write(&config.data_directory) {
+            Ok(_) => { },
+            Err(err) => {
+                eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Could not save opening book: {}", err)));
+            },
+        }
+        return book;
+    }
+
+    /// checks that the book was computed for the current word lists and settings
+    pub fn is_valid_for(self: &Self, config: &ConfigParams, matrix: &FeedbackMatrix) -> bool {
+        let size_of_word = utils::length_of_word(&self.opener);
+        return self.key == matrix.key()
+            && self.tactic == config.tactic
+            && self.hard_mode == config.hard_mode
+            && matrix.guesses().contains(&self.opener)
+            && self.replies.iter().all(|(&pattern, word)| {
+                (pattern as usize) < patterns::number_of_patterns(size_of_word)
+                && matrix.guesses().contains(word)
+            });
+    }
+
+    /// looks up the next guess, if the game is still within the book
+    pub fn lookup(self: &Self, history: &Vec<WordlState>) -> Option<String> {
+        match history.as_slice() {
+            [] => {
+                return Some(self.opener.clone());
+            },
+            [state] if state.to_word() == self.opener => {
+                return self.replies.get(&state.to_pattern()).cloned();
+            },
+            _ => {
+                return None;
+            },
+        }
+    }
+
+    fn read(path: &str) -> Result<Self, io::Error> {
+        let spec = utils::read_yaml(path)?;
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Opening book is malformed!");
+        let key = spec["key"].as_str().ok_or_else(invalid)?.to_string();
+        let tactic = spec["tactic"].as_str().and_then(Tactic::from_name).ok_or_else(invalid)?;
+        let hard_mode = spec["hard-mode"].as_bool().ok_or_else(invalid)?;
+        let opener = spec["opener"].as_str().ok_or_else(invalid)?.to_string();
+        let mut replies: BTreeMap<Pattern, String> = BTreeMap::new();
+        for (feedback, word) in spec["replies"].as_hash().ok_or_else(invalid)?.iter() {
+            let feedback = feedback.as_str().ok_or_else(invalid)?;
+            let word = word.as_str().ok_or_else(invalid)?;
+            if !(utils::length_of_word(&feedback.to_string()) == utils::length_of_word(&opener)) {
+                return Err(invalid());
+            }
+            replies.insert(patterns::pattern_from_feedback(feedback), word.to_string());
+        }
+        return Ok(OpeningBook { key, tactic, hard_mode, opener, replies });
+    }
+
+    fn write(self: &Self, path_to_directory: &str) -> Result<(), io::Error> {
+        std::fs::create_dir_all(path_to_directory)?;
+        let size_of_word = utils::length_of_word(&self.opener);
+        let mut replies = Hash::new();
+        for (&pattern, word) in self.replies.iter() {
+            replies.insert(
+                Yaml::String(patterns::pattern_to_feedback(pattern, size_of_word)),
+                Yaml::String(word.clone()),
+            );
+        }
+        let mut spec = Hash::new();
+        spec.insert(Yaml::String(String::from("key")), Yaml::String(self.key.clone()));
+        spec.insert(Yaml::String(String::from("tactic")), Yaml::String(self.tactic.name().to_string()));
+        spec.insert(Yaml::String(String::from("hard-mode")), Yaml::Boolean(self.hard_mode));
+        spec.insert(Yaml::String(String::from("opener")), Yaml::String(self.opener.clone()));
+        spec.insert(Yaml::String(String::from("replies")), Yaml::Hash(replies));
+        let path = get_path_to_book(path_to_directory, &self.key, &self.tactic, self.hard_mode);
+        return utils::write_yaml(&path, &Yaml::Hash(spec));
+    }
+}
+
+// ----------------------------------------------------------------
+// Auxiliary methods
+// ----------------------------------------------------------------
+
+fn get_path_to_book(path_to_directory: &str, key: &str, tactic: &Tactic, hard_mode: bool) -> String {
+    let mode = if hard_mode { "hard" } else { "normal" };
+    let filename = format!("book-{}-{}-{}.yml", key, tactic.name(), mode);
+    return Path::new(path_to_directory).join(filename).to_string_lossy().to_string();
+}
@@ -0,0 +1,120 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate yaml_rust;
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use self::yaml_rust::Yaml;
+use self::yaml_rust::yaml::Array;
+use self::yaml_rust::yaml::Hash;
+
+use crate::core::random::Random;
+use crate::core::utils;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+static FILENAME_RECORDS: &str = "daily.yml";
+
+// ----------------------------------------------------------------
+// Structure DailyRecord
+// ----------------------------------------------------------------
+
+/// The attempt at the daily puzzle of one date.
+pub struct DailyRecord {
+    pub number: i64,
+    pub guesses: Vec<String>,
+    pub finished: bool,
+}
+
+// ----------------------------------------------------------------
+// Methods - selection
+// ----------------------------------------------------------------
+
+/// chooses the word of a daily puzzle.
+///
+/// The solutions are sorted (so that the order of the file does not matter)
+/// and shuffled once by the seed. Puzzle `n` is the `n`-th word of this ordering,
+/// so that no word repeats before all solutions have been used.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::daily::daily_word;
+/// let solutions = vec![String::from("crane"), String::from("pilot"), String::from("tarse")];
+/// let mut reversed = solutions.clone();
+/// reversed.reverse();
+/// assert_eq!(daily_word(&solutions, 0, 7), daily_word(&reversed, 0, 7));
+/// assert_ne!(daily_word(&solutions, 0, 7), daily_word(&solutions, 0, 8));
+/// assert_eq!(daily_word(&solutions, 0, 7), daily_word(&solutions, 0, 10));
+/// assert_eq!(daily_word(&vec![], 0, 7), None);
+/// ```
+pub fn daily_word(solutions: &Vec<String>, seed: u64, number: i64) -> Option<String> {
+    let mut words = solutions.clone();
+    words.sort();
+    words.dedup();
+    if words.len() == 0 {
+        return None;
+    }
+    // Fisher-Yates shuffle:
+    let mut rng = Random::new(seed);
+    for i in (1..words.len()).rev() {
+        let j = rng.below(i + 1);
+        words.swap(i, j);
+    }
+    let index = number.rem_euclid(words.len() as i64) as usize;
+    return words.get(index).cloned();
+}
+
+// ----------------------------------------------------------------
+// Methods - records
+// ----------------------------------------------------------------
+
+pub fn get_path_to_records(path_to_directory: &str) -> String {
+    return Path::new(path_to_directory).join(FILENAME_RECORDS).to_string_lossy().to_string();
+}
+
+/// reads the attempts at daily puzzles, keyed by date (empty, if there are no records yet)
+pub fn read_records(path: &str) -> Result<BTreeMap<String, DailyRecord>, io::Error> {
+    let mut records: BTreeMap<String, DailyRecord> = BTreeMap::new();
+    if !Path::new(path).exists() {
+        return Ok(records);
+    }
+    let spec = utils::read_yaml(path)?;
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Daily records are malformed!");
+    for (date, record) in spec.as_hash().ok_or_else(invalid)?.iter() {
+        let date = date.as_str().ok_or_else(invalid)?.to_string();
+        let number = record["number"].as_i64().ok_or_else(invalid)?;
+        let guesses = record["guesses"].as_vec().ok_or_else(invalid)?
+            .iter()
+            .map(|guess| guess.as_str().map(|guess| guess.to_string()).ok_or_else(invalid))
+            .collect::<Result<Vec<String>, io::Error>>()?;
+        let finished = record["finished"].as_bool().ok_or_else(invalid)?;
+        records.insert(date, DailyRecord { number, guesses, finished });
+    }
+    return Ok(records);
+}
+
+pub fn write_records(path: &str, records: &BTreeMap<String, DailyRecord>) -> Result<(), io::Error> {
+    match Path::new(path).parent() {
+        Some(directory) => { std::fs::create_dir_all(directory)?; },
+        None => { },
+    }
+    let mut spec = Hash::new();
+    for (date, record) in records.iter() {
+        let guesses = record.guesses.iter()
+            .map(|guess| Yaml::String(guess.clone()))
+            .collect::<Array>();
+        let mut entry = Hash::new();
+        entry.insert(Yaml::String(String::from("number")), Yaml::Integer(record.number));
+        entry.insert(Yaml::String(String::from("guesses")), Yaml::Array(guesses));
+        entry.insert(Yaml::String(String::from("finished")), Yaml::Boolean(record.finished));
+        spec.insert(Yaml::String(date.clone()), Yaml::Hash(entry));
+    }
+    return utils::write_yaml(path, &Yaml::Hash(spec));
+}
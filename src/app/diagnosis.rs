@@ -0,0 +1,311 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::solver;
+use crate::app::states::WordlState;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+/// Sets of up to this many turns are searched for a contradiction
+/// (the number of sets grows exponentially with the length of the history).
+pub static MAX_TURNS_IN_CONTRADICTION: usize = 4;
+
+// ----------------------------------------------------------------
+// Structure Diagnosis
+// ----------------------------------------------------------------
+
+/// Explanation of why no words remain.
+pub enum Diagnosis {
+    /// The feedback of these turns (indexes into the history) contradicts itself,
+    /// whichever word is the answer.
+    Contradiction {
+        turns: Vec<usize>,
+        reason: String,
+        /// turns, whose feedback could be corrected so that possible solutions remain,
+        /// with the number of words then remaining, most likely fix first
+        /// (the most words remaining, then the latest turn)
+        fixes: Vec<(usize, usize)>,
+        /// whether no smaller set of turns contradicts itself
+        /// (`false`, if the search was stopped at `MAX_TURNS_IN_CONTRADICTION` turns and all turns are listed)
+        minimal: bool,
+    },
+    /// The feedback is consistent, so the answer is probably missing from the list of solutions.
+    /// Contains the allowed guesses (if any), which do fit the feedback.
+    MissingWord {
+        candidates: Vec<String>,
+    },
+}
+
+// ----------------------------------------------------------------
+// Methods
+// ----------------------------------------------------------------
+
+/// explains, why no words remain after the feedback in the history
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::diagnosis::diagnose;
+/// use wordle::app::diagnosis::Diagnosis;
+/// use wordle::app::patterns::FeedbackMatrix;
+/// use wordle::app::states::WordlState;
+/// let words = vec![String::from("crane"), String::from("pilot")];
+/// let matrix = FeedbackMatrix::new(&words, &words);
+/// // the first turn says there is an E at the end, the third that there is none:
+/// let history = vec![
+///     WordlState::new("crane", "xxxx1"),
+///     WordlState::new("pilot", "xxxxx"),
+///     WordlState::new("hoses", "xxxxx"),
+/// ];
+/// match diagnose(&matrix, &words, &history) {
+///     Diagnosis::Contradiction { turns, .. } => { assert_eq!(turns, vec![0, 2]); },
+///     Diagnosis::MissingWord { .. } => { panic!("expected a contradiction"); },
+/// }
+/// let history = vec![WordlState::new("crane", "xxxx1")];
+/// assert!(matches!(diagnose(&matrix, &words, &history), Diagnosis::MissingWord { .. }));
+///
+/// // the first turn says there is no A, the second that it is the 3rd letter;
+/// // correcting the first turn leaves more words (crate, grate, irate) than the second (cloth, clout):
+/// let words = vec![
+///     String::from("crate"), String::from("grate"), String::from("irate"),
+///     String::from("cloth"), String::from("clout"),
+/// ];
+/// let matrix = FeedbackMatrix::new(&words, &words);
+/// let history = vec![
+///     WordlState::new("crane", "1xxxx"),
+///     WordlState::new("slate", "xx111"),
+/// ];
+/// match diagnose(&matrix, &words, &history) {
+///     Diagnosis::Contradiction { fixes, .. } => { assert_eq!(fixes, vec![(0, 3), (1, 2)]); },
+///     Diagnosis::MissingWord { .. } => { panic!("expected a contradiction"); },
+/// }
+///
+/// // two different letters correct at the same position leave no words, which is diagnosed:
+/// use wordle::app::solver::get_words_remaining;
+/// use wordle::setup::assets::get_solutions;
+/// let solutions = get_solutions().unwrap();
+/// let matrix_nyt = FeedbackMatrix::new(&solutions, &solutions);
+/// let history = vec![WordlState::new("crane", "1xxxx"), WordlState::new("brine", "1xxxx")];
+/// assert_eq!(get_words_remaining(&solutions, &history).len(), 0);
+/// match diagnose(&matrix_nyt, &solutions, &history) {
+///     Diagnosis::Contradiction { turns, reason, .. } => {
+///         assert_eq!(turns, vec![0, 1]);
+///         assert_eq!(reason, "the 1st letter cannot be both B and C");
+///     },
+///     Diagnosis::MissingWord { .. } => { panic!("expected a contradiction"); },
+/// }
+///
+/// // six different letters in a word of five, which only all six turns together contradict:
+/// let history = ["abbbb", "cdddd", "effff", "ghhhh", "ijjjj", "kllll"].iter()
+///     .map(|guess| WordlState::new(guess, "-xxxx"))
+///     .collect::<Vec<WordlState>>();
+/// match diagnose(&matrix, &words, &history) {
+///     Diagnosis::Contradiction { turns, minimal, .. } => {
+///         assert_eq!(turns, vec![0, 1, 2, 3, 4, 5]);
+///         assert!(!minimal);
+///     },
+///     Diagnosis::MissingWord { .. } => { panic!("expected a contradiction"); },
+/// }
+/// ```
+pub fn diagnose(matrix: &FeedbackMatrix, solutions: &Vec<String>, history: &Vec<WordlState>) -> Diagnosis {
+    let size_of_word = history.iter().map(|state| state.len()).max().unwrap_or(0);
+    // if all turns together are consistent, so is every set of turns:
+    let states = history.iter().collect::<Vec<&WordlState>>();
+    let reason = match check_consistency(&states, size_of_word) {
+        Ok(_) => {
+            let candidates = solver::get_words_remaining(matrix.guesses(), history);
+            return Diagnosis::MissingWord { candidates };
+        },
+        Err(reason) => reason,
+    };
+    // search the smallest set of contradicting turns (stopping at the first size, which contradicts):
+    for k in 1..=history.len().min(MAX_TURNS_IN_CONTRADICTION) {
+        for turns in Subsets::new(history.len(), k) {
+            let states = turns.iter().map(|&t| &history[t]).collect::<Vec<&WordlState>>();
+            match check_consistency(&states, size_of_word) {
+                Ok(_) => { },
+                Err(reason) => {
+                    let fixes = get_fixes(solutions, history, &turns);
+                    return Diagnosis::Contradiction { turns, reason, fixes, minimal: true };
+                },
+            }
+        }
+    }
+    // the search was capped, so report all turns:
+    let turns = (0..history.len()).collect::<Vec<usize>>();
+    let fixes = get_fixes(solutions, history, &turns);
+    return Diagnosis::Contradiction { turns, reason, fixes, minimal: false };
+}
+
+/// checks whether some word (over an alphabet containing unconstrained letters)
+/// satisfies the constraints of all states.
+///
+/// ## Returns ##
+///
+/// `Err(reason)` describing the conflict, if not.
+pub fn check_consistency(states: &Vec<&WordlState>, size_of_word: usize) -> Result<(), String> {
+    // combine constraints per letter:
+    let mut required: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+    let mut forbidden: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+    let mut min: BTreeMap<String, usize> = BTreeMap::new();
+    let mut max: BTreeMap<String, usize> = BTreeMap::new();
+    for state in states.iter() {
+        for constraint in state.constraints.iter() {
+            let a = constraint.symbol.clone();
+            required.entry(a.clone()).or_insert(BTreeSet::new()).extend(constraint.indexes.iter());
+            forbidden.entry(a.clone()).or_insert(BTreeSet::new()).extend(constraint.non_indexes.iter());
+            let n = constraint.contains_min.max(0) as usize;
+            let min_ = min.entry(a.clone()).or_insert(0);
+            *min_ = (*min_).max(n);
+            if constraint.bounded {
+                let max_ = max.entry(a.clone()).or_insert(n);
+                *max_ = (*max_).min(n);
+            }
+        }
+    }
+    // each position holds one letter, which must not be excluded from it:
+    let mut letter_at: BTreeMap<usize, String> = BTreeMap::new();
+    for (a, indexes) in required.iter() {
+        for &index in indexes.iter() {
+            match letter_at.get(&index) {
+                Some(b) => {
+                    return Err(format!("the {} letter cannot be both {} and {}", ordinal_of_index(index), b, a));
+                },
+                None => { letter_at.insert(index, a.clone()); },
+            }
+            if forbidden.get(a).map(|indexes| indexes.contains(&index)).unwrap_or(false) {
+                return Err(format!("the {} letter is marked as {} and as not {}", ordinal_of_index(index), a, a));
+            }
+        }
+    }
+    // occurrences must be within bounds:
+    for (a, &n_max) in max.iter() {
+        let n_min = *min.get(a).unwrap_or(&0);
+        let n_required = required.get(a).map(|indexes| indexes.len()).unwrap_or(0);
+        if n_min > n_max {
+            return Err(format!("{} must occur at least {} but at most {} times", a, n_min, n_max));
+        }
+        if n_required > n_max {
+            return Err(format!("{} must occur at {} positions but at most {} times", a, n_required, n_max));
+        }
+    }
+    // the further occurrences must fit into the free positions:
+    let free = (0..size_of_word)
+        .filter(|index| !letter_at.contains_key(index))
+        .collect::<Vec<usize>>();
+    let mut demands = Vec::<(String, Vec<usize>)>::new();
+    for (a, &n_min) in min.iter() {
+        let n_required = required.get(a).map(|indexes| indexes.len()).unwrap_or(0);
+        let allowed = free.iter()
+            .cloned()
+            .filter(|index| !forbidden.get(a).map(|indexes| indexes.contains(index)).unwrap_or(false))
+            .collect::<Vec<usize>>();
+        for _ in n_required..n_min {
+            demands.push((a.clone(), allowed.clone()));
+        }
+    }
+    if !has_complete_matching(&demands) {
+        let letters = demands.iter().map(|(a, _)| a.clone()).collect::<BTreeSet<String>>();
+        let letters = letters.into_iter().collect::<Vec<String>>().join(", ");
+        return Err(format!("there are not enough positions left for the letters {}", letters));
+    }
+    return Ok(());
+}
+
+// ----------------------------------------------------------------
+// Auxiliary methods
+// ----------------------------------------------------------------
+
+fn ordinal_of_index(index: usize) -> String {
+    return crate::core::utils::ordinal(index + 1);
+}
+
+/// turns, whose feedback could be corrected so that possible solutions remain, most likely fix first
+fn get_fixes(solutions: &Vec<String>, history: &Vec<WordlState>, turns: &Vec<usize>) -> Vec<(usize, usize)> {
+    let mut fixes = turns.iter()
+        .map(|&t| {
+            let history_ = history.iter()
+                .enumerate()
+                .filter(|&(index, _)| !(index == t))
+                .map(|(_, state)| state.clone())
+                .collect::<Vec<WordlState>>();
+            return (t, solver::get_words_remaining(solutions, &history_).len());
+        })
+        .filter(|&(_, n)| n > 0)
+        .collect::<Vec<(usize, usize)>>();
+    // the more words fit the other turns, the likelier the mistake lies in this turn:
+    fixes.sort_by(|&(t1, n1), &(t2, n2)| n2.cmp(&n1).then(t2.cmp(&t1)));
+    return fixes;
+}
+
+/// Iterates over the subsets of `0..n` of size `k` in lexicographic order, one at a time.
+struct Subsets {
+    n: usize,
+    next: Option<Vec<usize>>,
+}
+
+impl Subsets {
+    fn new(n: usize, k: usize) -> Self {
+        let next = if k <= n { Some((0..k).collect::<Vec<usize>>()) } else { None };
+        return Subsets { n, next };
+    }
+}
+
+impl Iterator for Subsets {
+    type Item = Vec<usize>;
+
+    fn next(self: &mut Self) -> Option<Vec<usize>> {
+        let subset = self.next.take()?;
+        // advance the last index, which can still be increased, and reset those after it:
+        let k = subset.len();
+        let mut next = subset.clone();
+        match (0..k).rev().find(|&i| next[i] < self.n - k + i) {
+            Some(i) => {
+                next[i] += 1;
+                for j in (i + 1)..k {
+                    next[j] = next[j - 1] + 1;
+                }
+                self.next = Some(next);
+            },
+            None => { },
+        }
+        return Some(subset);
+    }
+}
+
+/// checks whether each demand can be assigned its own position from its allowed positions (augmenting paths)
+fn has_complete_matching(demands: &Vec<(String, Vec<usize>)>) -> bool {
+    let mut assigned: BTreeMap<usize, usize> = BTreeMap::new();
+    for d in 0..demands.len() {
+        let mut visited = BTreeSet::<usize>::new();
+        if !augment(d, demands, &mut assigned, &mut visited) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn augment(d: usize, demands: &Vec<(String, Vec<usize>)>, assigned: &mut BTreeMap<usize, usize>, visited: &mut BTreeSet<usize>) -> bool {
+    for &index in demands[d].1.iter() {
+        if visited.contains(&index) {
+            continue;
+        }
+        visited.insert(index);
+        let free = match assigned.get(&index) {
+            Some(&other) => augment(other, demands, assigned, visited),
+            None => true,
+        };
+        if free {
+            assigned.insert(index, d);
+            return true;
+        }
+    }
+    return false;
+}
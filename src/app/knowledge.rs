@@ -0,0 +1,226 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate dyn_fmt;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use self::dyn_fmt::AsStrFormatExt;
+
+use crate::core::utils;
+use crate::app::states::WordlState;
+
+// ----------------------------------------------------------------
+// Structure Knowledge
+// ----------------------------------------------------------------
+
+/// Everything known about the solution from the feedback of all turns, merged per letter.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::knowledge::Knowledge;
+/// use wordle::app::states::WordlState;
+/// let history = vec![
+///     WordlState::new("crane", "x-xx1"),
+///     WordlState::new("rouse", "-xxx1"),
+/// ];
+/// let knowledge = Knowledge::from_history(&history);
+/// assert!(knowledge.is_compatible_with(&String::from("there")));
+/// assert!(!knowledge.is_compatible_with(&String::from("tribe")));
+/// assert!(!knowledge.is_compatible_with(&String::from("serve")));
+/// let r = knowledge.letters.get("R").unwrap();
+/// assert_eq!(r.non_indexes, vec![0, 1]);
+/// assert_eq!((r.contains_min, r.contains_max), (1, None));
+/// assert_eq!(knowledge.letters.get("C").unwrap().contains_max, Some(0));
+/// assert!(!knowledge.contradictory);
+///
+/// // two different letters marked correct at the same position:
+/// let history = vec![
+///     WordlState::new("crane", "1xxxx"),
+///     WordlState::new("brine", "1xxxx"),
+/// ];
+/// let knowledge = Knowledge::from_history(&history);
+/// assert!(knowledge.contradictory);
+/// assert!(!knowledge.is_compatible_with(&String::from("butch")));
+
+/// // a repeated letter marked incorrect before it is marked partially correct:
+/// let knowledge = Knowledge::from_history(&vec![WordlState::new("allay", "-x-1x")]);
+/// assert!(knowledge.contradictory);
+/// ```
+#[derive(Clone)]
+pub struct Knowledge {
+    pub size_of_word: usize,
+    pub letters: BTreeMap<String, LetterKnowledge>,
+    // per position: letter known to be there, letters known not to be there
+    fixed: Vec<Option<String>>,
+    banned: Vec<BTreeSet<String>>,
+    /// whether the feedback cannot occur (e.g. two turns require different letters at the same position),
+    /// so that no word is compatible
+    pub contradictory: bool,
+}
+
+/// What is known about one letter (cf. `WordlConstraint`).
+#[derive(Clone)]
+pub struct LetterKnowledge {
+    pub symbol: String,
+    pub indexes: Vec<usize>,
+    pub non_indexes: Vec<usize>,
+    pub contains_min: usize,
+    /// `None` if the number of occurrences is not bounded
+    pub contains_max: Option<usize>,
+}
+
+// ----------------------------------------------------------------
+// Implementation LetterKnowledge
+// ----------------------------------------------------------------
+
+impl LetterKnowledge {
+    fn new(symbol: &String) -> Self {
+        return LetterKnowledge {
+            symbol: symbol.clone(),
+            indexes: vec![],
+            non_indexes: vec![],
+            contains_min: 0,
+            contains_max: None,
+        };
+    }
+
+    pub fn to_string(self: &Self) -> String {
+        if self.contains_max == Some(0) {
+            return format!("- `{}` does not occur.", self.symbol);
+        }
+        let max = self.contains_max.map(|n| n.to_string()).unwrap_or(String::from("?"));
+        return "- `{}`: at indexes {}; NOT at indexes {}; occurs {} to {} times."
+            .format(&[
+                self.symbol.clone(),
+                utils::array_to_string(&self.indexes),
+                utils::array_to_string(&self.non_indexes),
+                self.contains_min.to_string(),
+                max,
+            ]).to_string();
+    }
+}
+
+// ----------------------------------------------------------------
+// Implementation Knowledge
+// ----------------------------------------------------------------
+
+impl Knowledge {
+    pub fn new() -> Self {
+        return Knowledge {
+            size_of_word: 0,
+            letters: BTreeMap::new(),
+            fixed: vec![],
+            banned: vec![],
+            contradictory: false,
+        };
+    }
+
+    /// merges the feedback of all states in the history
+    pub fn from_history(history: &Vec<WordlState>) -> Self {
+        let mut knowledge = Knowledge::new();
+        for state in history.iter() {
+            knowledge.add(state);
+        }
+        return knowledge;
+    }
+
+    /// merges the constraints of one more turn into the knowledge
+    pub fn add(self: &mut Self, state: &WordlState) {
+        if state.len() > self.size_of_word {
+            self.size_of_word = state.len();
+            self.fixed.resize(self.size_of_word, None);
+            self.banned.resize(self.size_of_word, BTreeSet::new());
+        }
+        // the game marks repeated letters as partially correct before incorrect,
+        // so other feedback cannot occur, whichever word is the solution:
+        let mut incorrect = BTreeSet::<&String>::new();
+        for char_state in state.states.iter() {
+            if !char_state.correct {
+                incorrect.insert(&char_state.symbol);
+            } else if char_state.partial && incorrect.contains(&char_state.symbol) {
+                self.contradictory = true;
+            }
+        }
+        for constraint in state.constraints.iter() {
+            let a = &constraint.symbol;
+            let letter = self.letters.entry(a.clone()).or_insert(LetterKnowledge::new(a));
+            for &index in constraint.indexes.iter() {
+                if !letter.indexes.contains(&index) {
+                    letter.indexes.push(index);
+                }
+                match &self.fixed[index] {
+                    Some(b) if !(b == a) => { self.contradictory = true; },
+                    _ => { self.fixed[index] = Some(a.clone()); },
+                }
+            }
+            for &index in constraint.non_indexes.iter() {
+                if !letter.non_indexes.contains(&index) {
+                    letter.non_indexes.push(index);
+                }
+                self.banned[index].insert(a.clone());
+            }
+            letter.indexes.sort();
+            letter.non_indexes.sort();
+            let n = constraint.contains_min.max(0) as usize;
+            letter.contains_min = letter.contains_min.max(n);
+            if constraint.bounded {
+                letter.contains_max = Some(letter.contains_max.map(|m| m.min(n)).unwrap_or(n));
+            }
+        }
+    }
+
+    /// checks the word against all merged constraints in a single pass over its letters
+    pub fn is_compatible_with(self: &Self, word: &String) -> bool {
+        let chars = utils::chars_to_uppercase(utils::string_to_chars(word));
+        if self.contradictory || chars.len() < self.size_of_word {
+            return false;
+        }
+        let mut counts: BTreeMap<&String, usize> = BTreeMap::new();
+        for (index, a) in chars.iter().enumerate() {
+            if index < self.size_of_word {
+                if self.fixed[index].as_ref().map(|b| b != a).unwrap_or(false) || self.banned[index].contains(a) {
+                    return false;
+                }
+            }
+            *counts.entry(a).or_insert(0) += 1;
+        }
+        for letter in self.letters.values() {
+            let count = *counts.get(&letter.symbol).unwrap_or(&0);
+            if count < letter.contains_min || letter.contains_max.map(|n| count > n).unwrap_or(false) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /// reduces a list of words to those compatible with the knowledge
+    pub fn constrain(self: &Self, words: &Vec<String>) -> Vec<String> {
+        return words.iter()
+            .cloned()
+            .filter(|word| self.is_compatible_with(word))
+            .collect::<Vec<String>>();
+    }
+
+    /// summarises what is known, e.g.
+    ///
+    /// ```text
+    /// Knowledge: ????E
+    /// - `C` does not occur.
+    /// - `E`: at indexes [4]; NOT at indexes []; occurs 1 to 1 times.
+    /// - `R`: at indexes []; NOT at indexes [0, 1]; occurs 1 to ? times.
+    /// ```
+    pub fn to_string(self: &Self) -> String {
+        let pattern = self.fixed.iter()
+            .map(|a| a.clone().unwrap_or(String::from("?")))
+            .collect::<Vec<String>>()
+            .join("");
+        let mut lines = vec![format!("Knowledge: {}{}", pattern, if self.contradictory { " (contradictory)" } else { "" })];
+        for letter in self.letters.values() {
+            lines.push(letter.to_string());
+        }
+        return lines.join("\n");
+    }
+}
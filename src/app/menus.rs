@@ -5,25 +5,47 @@
 extern crate dyn_fmt;
 extern crate closure;
 
+use std::collections::HashMap;
 use self::dyn_fmt::AsStrFormatExt;
 
 use crate::core::utils;
+use crate::core::console;
+use crate::core::dates;
+use crate::core::random::Random;
 use crate::cli;
+use crate::cli::args::OutputFormat;
 use crate::display::basic::display_word;
-use crate::display::basic::display_words;
+use crate::display::basic::display_benchmark;
+use crate::display::basic::display_config;
+use crate::display::basic::display_diagnosis;
+use crate::display::basic::display_dictionary;
+use crate::display::basic::display_knowledge;
+use crate::display::basic::display_share;
+use crate::display::basic::display_state;
+use crate::display::basic::display_statistics;
+use crate::display::basic::display_suggestions;
+use crate::display::basic::display_tree_text;
+use crate::display::json::display_suggestions_json;
+use crate::display::json::display_tree_json;
+use crate::setup::assets;
+use crate::setup::config::ConfigLayer;
 use crate::setup::config::ConfigParams;
 use crate::app::validators::guess_validators;
+use crate::app::states::Board;
 use crate::app::states::WordlState;
-use crate::app::tactics;
-
-// ----------------------------------------------------------------
-// Constants
-// ----------------------------------------------------------------
-
-#[allow(dead_code)]
-static EXAMPLE_GUESS: &str = "alert";
-#[allow(dead_code)]
-static EXAMPLE_FEEDBACK: &str = "xx-x1";
+use crate::app::absurdle;
+use crate::app::bench;
+use crate::app::daily;
+use crate::app::diagnosis;
+use crate::app::knowledge::Knowledge;
+use crate::app::patterns;
+use crate::app::priors::Priors;
+use crate::app::session::Session;
+use crate::app::solver;
+use crate::app::stats;
+use crate::app::tactics::selection::Tactic;
+use crate::app::tree;
+use crate::app::tree::DecisionTree;
 
 // ----------------------------------------------------------------
 // Title screens
@@ -31,7 +53,7 @@ static EXAMPLE_FEEDBACK: &str = "xx-x1";
 
 pub fn show_start_screen(config: &ConfigParams) {
     println!("");
-    println!("{}", utils::dedent_ignore_first_last(
+    println!("{}", console::paint(&utils::dedent_ignore_first_last(
         "
         * --------------------------------
         | \x1b[92;1m{}\x1b[0m
@@ -46,16 +68,16 @@ pub fn show_start_screen(config: &ConfigParams) {
         &config.version,
         &config.url,
         "",
-    ]));
+    ])));
 
     if !(&config.notes == "") {
-        println!("\x1b[2mNOTE: {}\x1b[0m", &config.notes);
+        println!("{}", console::paint(&format!("\x1b[2mNOTE: {}\x1b[0m", &config.notes)));
     }
 }
 
 pub fn show_end_screen(config: &ConfigParams) {
     println!("");
-    println!("{}", utils::dedent_ignore_first_last(
+    println!("{}", console::paint(&utils::dedent_ignore_first_last(
         "
         Thank you for using
 
@@ -74,89 +96,353 @@ pub fn show_end_screen(config: &ConfigParams) {
         &config.version,
         &config.url,
         "",
-    ]));
+    ])));
 }
 
 // ----------------------------------------------------------------
-// Main menu
+// Suggestions (non-interactive)
+// ----------------------------------------------------------------
+
+/// prints the ranked suggestions once (without prompting), given previous guesses and their feedback
+pub fn show_suggestions(
+    config: &ConfigParams,
+    words: &Vec<String>,
+    solutions: &Vec<String>,
+    priors: &Priors,
+    history: &Vec<WordlState>,
+    format: &OutputFormat,
+) {
+    let (guesses, solutions_) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let matrix = solver::get_feedback_matrix(config, &guesses, &solutions_, priors);
+    let book = solver::get_opening_book(config, &matrix);
+    let words_remaining = solver::get_words_remaining(&solutions_, history);
+    let suggestions = match words_remaining.len() {
+        0 | 1 => words_remaining.clone(),
+        _ => solver::get_suggestions_with_book(config, &matrix, &book, history, &words_remaining),
+    };
+    match format {
+        OutputFormat::Json => {
+            let suggestions = suggestions.iter()
+                .take(config.max_display_length)
+                .cloned()
+                .collect::<Vec<String>>();
+            let (tactic, scores) = solver::get_scores(config, &matrix, &suggestions, &words_remaining);
+            let probabilities = matrix.priors().probabilities(&words_remaining);
+            println!("{}", display_suggestions_json(&tactic, history, &suggestions, &scores, &words_remaining, &probabilities));
+        },
+        OutputFormat::Text => {
+            for state in history.iter() {
+                println!("{}", display_state(state));
+            }
+            if words_remaining.len() == 0 {
+                eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] No solution found, as there are no words remaining!"));
+                if history.len() > 0 {
+                    display_diagnosis(&diagnosis::diagnose(&matrix, &solutions_, history), history, config.max_display_length);
+                }
+                return;
+            }
+            display_suggestions(&suggestions, &words_remaining, &matrix.priors().probabilities(&words_remaining), config.max_display_length);
+        },
+    }
+}
+
+// ----------------------------------------------------------------
+// Statistics (non-interactive)
+// ----------------------------------------------------------------
+
+/// prints the statistics recorded for each kind of game
+pub fn show_statistics(config: &ConfigParams) -> Result<(), String> {
+    let path = stats::get_path_to_statistics(&config.data_directory);
+    let statistics = stats::read_statistics(&path)
+        .map_err(|err| format!("Could not read statistics `{}`: {}", path, err))?;
+    if statistics.len() == 0 {
+        println!("No games recorded yet.");
+        return Ok(());
+    }
+    for mode in stats::MODES.iter() {
+        match statistics.get(*mode) {
+            Some(entry) => { display_statistics(mode, entry); },
+            None => { },
+        }
+    }
+    return Ok(());
+}
+
+// ----------------------------------------------------------------
+// Dictionaries (non-interactive)
+// ----------------------------------------------------------------
+
+/// lists the bundled word lists with their number of words and the lengths of their words
+pub fn show_dictionaries() -> Result<(), String> {
+    for &(name, filename, description) in assets::DICTIONARIES.iter() {
+        let words = assets::get_dictionary(name)
+            .map_err(|err| format!("Could not read dictionary `{}`: {}", name, err))?;
+        let lengths = assets::count_lengths(&words);
+        display_dictionary(name, filename, description, words.len(), &lengths);
+    }
+    return Ok(());
+}
+
+// ----------------------------------------------------------------
+// Config (non-interactive)
 // ----------------------------------------------------------------
 
-pub fn main_menu(config: &ConfigParams, words: &Vec<String>) {
-    let mut state = WordlState::empty();
-    // first restrict list of words to appropriate size:
-    let mut words_remaining = words.clone()
-        .iter()
-        .filter(|&word| (word.len() == config.size_of_wordle))
-        .map(|word| (word.clone()))
+/// prints the settings in effect and where each value came from
+pub fn show_config(config: &ConfigParams) {
+    display_config(config);
+}
+
+/// confirms that the layers of settings are valid
+pub fn show_config_check(layers: &Vec<ConfigLayer>) {
+    let sources = layers.iter()
+        .map(|layer| layer.source.clone())
         .collect::<Vec<String>>();
-    let mut summary = Vec::<String>::new();
+    println!("{}", console::paint(&format!("\x1b[92;1mOK\x1b[0m The settings are valid (checked: {}).", sources.join(", "))));
+}
 
-    // Main cycle:
-    while words_remaining.len() > 1 {
-        // sort word list by best guesses:
-        let mut sorted_by_best = false;
-        if words_remaining.len() <= config.max_length_for_best_optimisation {
-            // tactics::advanced::reduce_sort_by_remaining_size_then_entropy_then_uniqueness(&mut words_remaining);
-            tactics::advanced::reduce_sort_by_distance_then_entropy_then_uniqueness(&mut words_remaining);
-        } else {
-            tactics::basic::reduce_sort_by_entropy_then_uniqueness(&mut words_remaining);
-            sorted_by_best = false;
+// ----------------------------------------------------------------
+// Benchmarks (non-interactive)
+// ----------------------------------------------------------------
+
+/// lets the solver play against (the first `limit` of) the solutions for each tactic and first guess
+pub fn show_benchmarks(
+    config: &ConfigParams,
+    words: &Vec<String>,
+    solutions: &Vec<String>,
+    priors: &Priors,
+    tactics: &Vec<Tactic>,
+    starts: &Vec<Option<String>>,
+    limit: usize,
+) {
+    let (guesses, solutions) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let matrix = solver::get_feedback_matrix(config, &guesses, &solutions, priors);
+    let targets = if limit > 0 { solutions.iter().take(limit).cloned().collect() } else { solutions.clone() };
+    for tactic in tactics.iter() {
+        for start in starts.iter() {
+            let mut config = config.clone();
+            config.tactic = *tactic;
+            let result = bench::run_benchmark(&config, &matrix, &solutions, &targets, start);
+            display_benchmark(&result);
         }
+    }
+}
 
-        // display best guesses:
-        let n_remaining = words_remaining.len();
-        let words_unique: Vec<String>;
-        let mut suggestion = words_remaining.get(0).map(|word| word.clone());
-        if sorted_by_best || n_remaining <= config.max_display_length {
-            display_words(&words_remaining, n_remaining, config.max_display_length);
-        } else {
-            words_unique = tactics::basic::reduce_to_words_with_unique_letters(&words_remaining);
-            if words_unique.len() > 0 {
-                display_words(&words_unique, n_remaining, config.max_display_length);
-                suggestion = words_unique.get(0).map(|word| word.clone());
-            } else {
-                display_words(&words_remaining, n_remaining, config.max_display_length);
+// ----------------------------------------------------------------
+// Decision trees (non-interactive)
+// ----------------------------------------------------------------
+
+/// builds a decision tree and prints it (or writes it to a file)
+pub fn show_tree(
+    config: &ConfigParams,
+    words: &Vec<String>,
+    solutions: &Vec<String>,
+    priors: &Priors,
+    start: &Option<String>,
+    breadth: usize,
+    max_depth: usize,
+    path: &Option<String>,
+    format: &OutputFormat,
+) -> Result<(), String> {
+    let (guesses, solutions) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let matrix = solver::get_feedback_matrix(config, &guesses, &solutions, priors);
+    let start = match start {
+        Some(word) => word.clone(),
+        None => solver::get_suggestions(config, &matrix, &vec![], &solutions)
+            .get(0)
+            .cloned()
+            .ok_or_else(|| String::from("No words available to start with."))?,
+    };
+    let tree = tree::build_tree(config, &matrix, &solutions, &start, breadth, max_depth)?;
+    let contents = match format {
+        OutputFormat::Json => display_tree_json(&config.tactic, &tree),
+        OutputFormat::Text => display_tree_text(&tree),
+    };
+    match path {
+        Some(path) => {
+            std::fs::write(utils::expand_home_directory(path), contents + "\n")
+                .map_err(|err| format!("Could not write decision tree to `{}`: {}", path, err))?;
+            eprintln!(
+                "{}",
+                console::paint(&format!(
+                    "Decision tree starting with \x1b[1m{}\x1b[0m (at most {} guesses, {:.4} on average) written to \x1b[4m{}\x1b[0m.",
+                    start,
+                    tree.depth(),
+                    (tree.total_guesses() as f64)/(tree.remaining.max(1) as f64),
+                    path,
+                ))
+            );
+        },
+        None => {
+            println!("{}", contents);
+        },
+    }
+    return Ok(());
+}
+
+// ----------------------------------------------------------------
+// Main menu
+// ----------------------------------------------------------------
+
+/// runs the interactive solver
+///
+/// ## Arguments ##
+///
+/// - `config` - settings
+/// - `words` - list of allowed guesses
+/// - `solutions` - list of possible solutions
+/// - `priors` - how likely each solution is (see `Priors::uniform` for none)
+/// - `tree` - optional decision tree to follow instead of ranking guesses
+///   (as long as the guesses made agree with it)
+/// - `session` - optional saved session to carry on from (with its tactic)
+pub fn main_menu(
+    config: &ConfigParams,
+    words: &Vec<String>,
+    solutions: &Vec<String>,
+    priors: &Priors,
+    tree: &Option<DecisionTree>,
+    session: Option<Session>,
+) {
+    let mut config = config.clone();
+    let mut history = Vec::<WordlState>::new();
+    // first restrict lists of words to appropriate size:
+    let (guesses, solutions_) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let mut words_remaining = solutions_.clone();
+    let matrix = solver::get_feedback_matrix(&config, &guesses, &solutions_, priors);
+
+    // carry on from saved session (with its tactic), provided it was for the same word lists:
+    let mut save_session = true;
+    match session {
+        Some(session) if session.key == matrix.key() => {
+            println!("{}", console::paint(&format!("\nResuming session after {} guesses:\n", session.history.len())));
+            for state in session.history.iter() {
+                println!("{}", display_state(state));
             }
-        }
+            if !(session.tactic == config.tactic) {
+                println!("{}", console::paint(&format!("\x1b[2mContinuing with the tactic of the session ({}).\x1b[0m", session.tactic.name())));
+                config.tactic = session.tactic;
+                config.sources.insert(String::from("tactic"), String::from("session"));
+            }
+            history = session.history;
+            words_remaining = solver::get_words_remaining(&words_remaining, &history);
+        },
+        Some(_) => {
+            // do not overwrite the saved session, so that it can be resumed with its own word lists:
+            eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] The saved session was for different word lists. It is kept, but this game will not be saved."));
+            save_session = false;
+        },
+        None => { },
+    }
+    let config = &config;
+    let book = match tree {
+        Some(_) => None,
+        None => solver::get_opening_book(config, &matrix),
+    };
 
-        // ask for next guess + feedback from game:
-        loop {
-            let (state_, _, cancel, quit) = sub_menu_next_guess(config, &suggestion);
-            state = state_;
-            if quit {
+    // Main cycle:
+    while !(words_remaining.len() == 1 || history.last().map(|state| state.is_correct()).unwrap_or(false)) {
+        let suggestions = if words_remaining.len() == 0 {
+            // no way forward, unless a previous feedback is corrected:
+            if history.len() == 0 {
+                break;
+            }
+            eprintln!("{}", console::paint("\n[\x1b[93;1mWARNING\x1b[0m] No words remaining! Undo or edit the feedback of a previous guess."));
+            display_diagnosis(&diagnosis::diagnose(&matrix, &solutions_, &history), &history, config.max_display_length);
+            vec![]
+        } else {
+            // follow decision tree, if possible, otherwise sort list of guesses by best guesses:
+            // (in hard mode only as long as its guesses use every revealed hint)
+            let node = tree.as_ref()
+                .and_then(|tree| tree.follow(&history))
+                .filter(|node| !config.hard_mode || history.iter().all(|state| state.hard_mode_violation(&node.guess).is_none()));
+            let suggestions = match node {
+                Some(node) => vec![node.guess.clone()],
+                None => solver::get_suggestions_with_book(config, &matrix, &book, &history, &words_remaining),
+            };
+            // display best guesses:
+            display_suggestions(&suggestions, &words_remaining, &matrix.priors().probabilities(&words_remaining), config.max_display_length);
+            suggestions
+        };
+        let suggestion = suggestions.get(0).map(|word| word.clone());
+
+        // ask for next guess + feedback from game (or corrections of previous guesses):
+        let action = loop {
+            match sub_menu_next_guess(config, &history, &suggestion) {
+                TurnAction::Cancel => { continue; },
+                action => { break action; },
+            }
+        };
+        match action {
+            TurnAction::Guess(state) => {
+                println!("\nThe current state is: {}.", display_state(&state));
+                history.push(state);
+            },
+            TurnAction::Undo => {
+                match history.pop() {
+                    Some(state) => { println!("\nRemoved the last guess {}.", display_state(&state)); },
+                    None => { println!("\nThere are no guesses to undo."); },
+                }
+            },
+            TurnAction::Edit(index, state) => {
+                println!("\nThe {} guess is now: {}.", utils::ordinal(index + 1), display_state(&state));
+                history[index] = state;
+            },
+            TurnAction::Cancel => { },
+            TurnAction::Quit => {
                 return;
-            } else if cancel {
-                continue;
+            },
+        }
+        // update state (recomputed from the whole history, so that corrections take effect):
+        if history.len() > 0 {
+            display_knowledge(&Knowledge::from_history(&history));
+        }
+        words_remaining = solver::get_words_remaining(&solutions_, &history);
+        // save session after every turn:
+        if save_session {
+            let session = Session { key: matrix.key(), tactic: config.tactic, history: history.clone() };
+            match session.save(&config.data_directory) {
+                Ok(_) => { },
+                Err(err) => {
+                    eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Could not save session: {}", err)));
+                },
             }
-            break;
         }
-        let feedback = state.to_string_with_feedback();
-        let feedback_anon = state.to_string_with_feedback_anon();
-        println!("\nThe current state is: {}.", feedback);
-        summary.push(if config.anonymous_feedback { feedback_anon } else { feedback });
-        // update state:
-        words_remaining = state.constrain(&words_remaining);
+    }
+    // game is over, so nothing remains to be resumed:
+    if save_session {
+        match Session::clear(&config.data_directory) {
+            Ok(_) => { },
+            Err(err) => {
+                eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Could not remove session: {}", err)));
+            },
+        }
     }
 
     // Handle final state:
     println!("");
-    match words_remaining.get(0) {
+    let solution = match history.last() {
+        Some(state) if state.is_correct() => Some(state.to_word()),
+        _ => words_remaining.get(0).cloned(),
+    };
+    match solution {
         Some(word) => {
             // if last state was incorrect and an option remains, then add in missing feedback, as loop terminated
-            if !state.is_correct() && words_remaining.len() > 0 {
-                let state = WordlState::from(word, word);
-                let feedback = state.to_string_with_feedback();
-                let feedback_anon = state.to_string_with_feedback_anon();
-                summary.push(if config.anonymous_feedback { feedback_anon } else { feedback });
+            let mut states = history.iter().collect::<Vec<&WordlState>>();
+            let state_final = WordlState::from(&word, &word);
+            let solved = history.last().map(|state| state.is_correct()).unwrap_or(false);
+            if !solved {
+                states.push(&state_final);
             }
+            // record result (games needing more than the permitted number of guesses count as lost):
+            let n = states.len();
+            stats::record_game(config, "solve", if n <= config.max_guesses { Some(n) } else { None });
             // display summary:
-            println!("\nThe solution is \x1b[1m{}\x1b[0m and your path to the solution was as follows:\n", word);
-            for feedback in summary.iter() {
-                println!("{}", feedback);
+            println!("{}", console::paint(&format!("\nThe solution is \x1b[1m{}\x1b[0m and your path to the solution was as follows:\n", word)));
+            for state in states.iter() {
+                println!("{}", if config.anonymous_feedback { state.to_string_with_feedback_anon() } else { display_state(state) });
             }
         },
         None => {
-            eprintln!("[\x1b[93;1mWARNING\x1b[0m] No solution found, as there are no words remaining!");
+            eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] No solution found, as there are no words remaining!"));
         },
     }
 
@@ -166,54 +452,493 @@ pub fn main_menu(config: &ConfigParams, words: &Vec<String>) {
     if response.cancel || response.quit {
         return;
     } else if response.state {
-        main_menu(config, words);
+        main_menu(config, words, solutions, priors, tree, None);
     }
 }
 
-fn sub_menu_next_guess(config: &ConfigParams, suggestion: &Option<String>) -> (WordlState, String, bool, bool) {
-    // let example: WordlState = WordlState::new(EXAMPLE_GUESS, EXAMPLE_FEEDBACK);
+// ----------------------------------------------------------------
+// Play menu
+// ----------------------------------------------------------------
+
+/// plays a game, in which the programme hides a word from the solutions for the user to guess
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (number of attempts, hard mode)
+/// - `words` - list of allowed guesses
+/// - `solutions` - list of words, from which the secret word is chosen
+/// - `seed` - optional seed for choosing the secret word (otherwise chosen at random)
+pub fn play_menu(config: &ConfigParams, words: &Vec<String>, solutions: &Vec<String>, seed: Option<u64>) {
+    let mut history = Vec::<WordlState>::new();
+    let (guesses, solutions_) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let mut rng = match seed {
+        Some(seed) => Random::new(seed),
+        None => Random::from_time(),
+    };
+    let secret = match solutions_.len() {
+        0 => {
+            eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] No words available to play with!"));
+            return;
+        },
+        n => solutions_[rng.below(n)].clone(),
+    };
+    if !sub_menu_play_game(config, &guesses, &secret, &mut history, |_| {}) {
+        return;
+    }
+    stats::record_game(config, "play", get_result(&history));
+    display_share(&String::from("Wordle"), &history, config.max_guesses, config.hard_mode);
+    if !history.last().map(|state| state.is_correct()).unwrap_or(false) {
+        println!("{}", console::paint(&format!("The word was \x1b[1m{}\x1b[0m.", secret.to_uppercase())));
+    }
 
-    // give user option to select top guess:
-    let response = cli::prompt::confirm("Choose the top suggestion as your next guess? (y/n) >> ");
+    // Prompt to try again:
+    println!("");
+    let response = cli::prompt::confirm("Would you like to try again? (y/n) >> ");
     if response.cancel || response.quit {
-        return (WordlState::empty(), String::from(""), response.cancel, response.quit);
+        return;
+    } else if response.state {
+        // NOTE: a seeded game is not repeated, but continues the sequence of secret words.
+        play_menu(config, words, solutions, seed.map(|_| rng.next_u64()));
     }
-    let guess: String;
-    if response.state {
-        match suggestion {
-            Some(word) => {
-                guess = word.clone();
-            },
-            // this should not happen!
-            None => {
-                panic!("This should not happen!");
-            },
+}
+
+// ----------------------------------------------------------------
+// Daily menu
+// ----------------------------------------------------------------
+
+/// plays the puzzle of the day, which is the same for everyone with the same word list and settings.
+/// Only one attempt per day is permitted (recorded in the data directory).
+pub fn daily_menu(config: &ConfigParams, words: &Vec<String>, solutions: &Vec<String>) -> Result<(), String> {
+    let (guesses, solutions_) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let epoch = dates::parse_date(&config.daily_epoch)
+        .ok_or_else(|| format!("The setting daily-epoch `{}` must be a date of the form YYYY-MM-DD.", config.daily_epoch))?;
+    let today = dates::today();
+    let date = dates::format_date(today);
+    let number = today - epoch;
+    let secret = daily::daily_word(&solutions_, config.daily_seed, number)
+        .ok_or_else(|| String::from("No words available to play with!"))?;
+    let path = daily::get_path_to_records(&config.data_directory);
+    let mut records = daily::read_records(&path)
+        .map_err(|err| format!("Could not read daily records `{}`: {}", path, err))?;
+    let title = format!("Wordle #{}", number);
+
+    // only one attempt per day:
+    match records.get(&date) {
+        Some(record) => {
+            let history = record.guesses.iter()
+                .map(|guess| WordlState::from(guess, &secret))
+                .collect::<Vec<WordlState>>();
+            println!("{}", console::paint(&format!("\nYou have already attempted the puzzle of {} (\x1b[1m#{}\x1b[0m).", date, record.number)));
+            if !record.finished {
+                println!("{}", console::paint("\x1b[2mThe attempt was abandoned.\x1b[0m"));
+            }
+            display_share(&title, &history, config.max_guesses, config.hard_mode);
+            return Ok(());
+        },
+        None => { },
+    }
+
+    println!("{}", console::paint(&format!("\nPuzzle of {} (\x1b[1m#{}\x1b[0m).", date, number)));
+    let mut history = Vec::<WordlState>::new();
+    let mut error: Option<String> = None;
+    let completed = sub_menu_play_game(config, &guesses, &secret, &mut history, |history| {
+        // record every guess, so that an abandoned attempt still counts:
+        let finished = history.len() >= config.max_guesses || history.last().map(|state| state.is_correct()).unwrap_or(false);
+        let guesses = history.iter().map(|state| state.to_word()).collect::<Vec<String>>();
+        records.insert(date.clone(), daily::DailyRecord { number, guesses, finished });
+        match daily::write_records(&path, &records) {
+            Ok(_) => { },
+            Err(err) => { error = Some(format!("Could not save daily record `{}`: {}", path, err)); },
+        }
+    });
+    match error {
+        Some(err) => { return Err(err); },
+        None => { },
+    }
+    if completed {
+        stats::record_game(config, "daily", get_result(&history));
+        display_share(&title, &history, config.max_guesses, config.hard_mode);
+        if !history.last().map(|state| state.is_correct()).unwrap_or(false) {
+            println!("{}", console::paint(&format!("The word was \x1b[1m{}\x1b[0m.", secret.to_uppercase())));
         }
-    // otherwise ask for input:
-    } else {
+    } else if history.len() > 0 {
+        // the attempt is recorded and cannot be retried, so counts as lost:
+        stats::record_game(config, "daily", None);
+    }
+    return Ok(());
+}
+
+// ----------------------------------------------------------------
+// Absurdle menu
+// ----------------------------------------------------------------
+
+/// plays ‘Absurdle‘, in which the programme keeps as many words in play as possible after each guess
+pub fn absurdle_menu(config: &ConfigParams, words: &Vec<String>, solutions: &Vec<String>) {
+    let mut history = Vec::<WordlState>::new();
+    let (guesses, mut words_remaining) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    // (the opponent does not care how common the words are)
+    let matrix = solver::get_feedback_matrix(config, &guesses, &words_remaining, &Priors::uniform());
+    println!("{}", console::paint(&format!("\nThe opponent can choose from \x1b[1m{}\x1b[0m words.", words_remaining.len())));
+
+    // Main cycle:
+    while !history.last().map(|state| state.is_correct()).unwrap_or(false) {
+        let guesses_ = guesses.clone();
+        let history_ = history.clone();
         let response = cli::prompt::input(
             "\nEnter your guess >> ",
             // validator:
-            closure::closure!(move config, |guess: &String| {
-                return guess_validators::validate_guess(guess, &config);
+            closure::closure!(move config, move history_, move guesses_, |guess: &String| {
+                return guess_validators::validate_guess(guess, &config, &history_)
+                    && guess_validators::validate_guess_in_list(guess, &guesses_);
+            })
+        );
+        if response.cancel {
+            continue;
+        } else if response.quit {
+            return;
+        }
+        let guess = response.state.to_lowercase();
+        let (pattern, words_remaining_) = absurdle::choose_feedback(&matrix, &guess, &words_remaining);
+        words_remaining = words_remaining_;
+        let state = WordlState::new(&guess, &patterns::pattern_to_feedback(pattern, config.size_of_wordle));
+        println!("{}", console::paint(&format!("\n{}  \x1b[2m{} remaining\x1b[0m", display_state(&state), words_remaining.len())));
+        history.push(state);
+    }
+
+    // display summary:
+    println!("{}", console::paint(&format!("\nYou beat Absurdle in \x1b[1m{}\x1b[0m guesses:\n", history.len())));
+    for state in history.iter() {
+        println!("{}", if config.anonymous_feedback { state.to_string_with_feedback_anon() } else { display_state(state) });
+    }
+
+    // Prompt to try again:
+    println!("");
+    let response = cli::prompt::confirm("Would you like to try again? (y/n) >> ");
+    if response.cancel || response.quit {
+        return;
+    } else if response.state {
+        absurdle_menu(config, words, solutions);
+    }
+}
+
+// ----------------------------------------------------------------
+// Multi-board menu
+// ----------------------------------------------------------------
+
+/// runs the interactive solver for several boards sharing each guess (as in Dordle, Quordle, Octordle)
+///
+/// ## Arguments ##
+///
+/// - `config` - settings
+/// - `words` - list of allowed guesses
+/// - `solutions` - list of possible solutions
+/// - `priors` - how likely each solution is (see `Priors::uniform` for none)
+/// - `number_of_boards` - number of boards played simultaneously
+pub fn multi_board_menu(config: &ConfigParams, words: &Vec<String>, solutions: &Vec<String>, priors: &Priors, number_of_boards: usize) {
+    // first restrict lists of words to appropriate size:
+    let (guesses, solutions_) = assets::get_word_lists(words, solutions, config.size_of_wordle);
+    let matrix = solver::get_feedback_matrix(config, &guesses, &solutions_, priors);
+    // every board starts out alike, so the opening book applies to the first guess:
+    let book = solver::get_opening_book(config, &matrix);
+    let mut boards = (0..number_of_boards)
+        .map(|_| Board::new(&solutions_))
+        .collect::<Vec<Board>>();
+    let mut turn = 0;
+
+    // Main cycle:
+    while boards.iter().any(|board| !board.is_solved()) {
+        if boards.iter().any(|board| board.words_remaining.len() == 0) {
+            eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] No solution found, as there are no words remaining on some board!"));
+            break;
+        }
+        // sort list of guesses by best guesses across the unsolved boards:
+        let boards_remaining = boards.iter()
+            .filter(|board| !board.is_solved())
+            .map(|board| board.words_remaining.clone())
+            .collect::<Vec<Vec<String>>>();
+        let suggestions = match (turn, book.as_ref()) {
+            (0, Some(book)) => vec![book.opener.clone()],
+            _ => solver::get_suggestions_for_boards(config, &matrix, &boards_remaining),
+        };
+        let suggestion = suggestions.get(0).map(|word| word.clone());
+        // display boards and best guesses:
+        println!("");
+        for (index, board) in boards.iter().enumerate() {
+            match board.history.last() {
+                Some(state) if board.is_solved() => {
+                    println!("{}", console::paint(&format!("  Board {}: solved ({})", index + 1, display_state(state))));
+                },
+                _ => {
+                    println!("  Board {}: {} remaining", index + 1, board.words_remaining.len());
+                },
+            }
+        }
+        let mut words_remaining = boards_remaining.into_iter().flatten().collect::<Vec<String>>();
+        words_remaining.sort();
+        words_remaining.dedup();
+        // (the remaining words of several boards have no common probabilities):
+        display_suggestions(&suggestions, &words_remaining, &HashMap::new(), config.max_display_length);
+
+        // ask for next guess (hard mode does not apply to the multi-board variants):
+        let (guess, cancel, quit) = sub_menu_choose_guess(config, &vec![], &guesses, &suggestion);
+        if quit {
+            return;
+        } else if cancel {
+            continue;
+        }
+        // ask for feedback from each unsolved board:
+        for (index, board) in boards.iter_mut().enumerate() {
+            if board.is_solved() {
+                continue;
+            }
+            let feedback = loop {
+                let (feedback, cancel, quit) = sub_menu_feedback(config, &guess, &format!(" on board {}", index + 1));
+                if quit {
+                    return;
+                } else if cancel {
+                    continue;
+                }
+                break feedback;
+            };
+            let state = WordlState::new(guess.as_str(), feedback.as_str());
+            println!("\nThe current state of board {} is: {}.", index + 1, display_state(&state));
+            board.update(state);
+        }
+        turn += 1;
+    }
+
+    // display summary:
+    println!("");
+    for (index, board) in boards.iter().enumerate() {
+        println!("{}", console::paint(&format!("\n\x1b[4mBoard {}\x1b[0m\n", index + 1)));
+        for state in board.history.iter() {
+            println!("{}", if config.anonymous_feedback { state.to_string_with_feedback_anon() } else { display_state(state) });
+        }
+    }
+
+    // Prompt to try again:
+    println!("");
+    let response = cli::prompt::confirm("Would you like to try again? (y/n) >> ");
+    if response.cancel || response.quit {
+        return;
+    } else if response.state {
+        multi_board_menu(config, words, solutions, priors, number_of_boards);
+    }
+}
+
+/// number of guesses needed, if the last guess was correct (`None` otherwise)
+fn get_result(history: &Vec<WordlState>) -> Option<usize> {
+    if history.last().map(|state| state.is_correct()).unwrap_or(false) {
+        return Some(history.len());
+    }
+    return None;
+}
+
+/// lets the user guess a secret word (until solved or out of attempts)
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (number of attempts, hard mode)
+/// - `guesses` - list of allowed guesses
+/// - `secret` - the word to be guessed
+/// - `history` - states of previous guesses (updated by each guess)
+/// - `after_guess` - called with the history after each guess
+///
+/// ## Returns ##
+///
+/// `false` if the user quit before the game was over.
+fn sub_menu_play_game<F>(
+    config: &ConfigParams,
+    guesses: &Vec<String>,
+    secret: &String,
+    history: &mut Vec<WordlState>,
+    mut after_guess: F,
+) -> bool
+    where F: FnMut(&Vec<WordlState>)
+{
+    let mode = if config.hard_mode { " (hard mode)" } else { "" };
+    println!("{}", console::paint(&format!("\nGuess the word in \x1b[1m{}\x1b[0m attempts{}.", config.max_guesses, mode)));
+    while history.len() < config.max_guesses && !history.last().map(|state| state.is_correct()).unwrap_or(false) {
+        let guesses_ = guesses.clone();
+        let history_ = history.clone();
+        let response = cli::prompt::input(
+            &format!("\nEnter your guess ({}/{}) >> ", history.len() + 1, config.max_guesses),
+            // validator:
+            closure::closure!(move config, move history_, move guesses_, |guess: &String| {
+                return guess_validators::validate_guess(guess, &config, &history_)
+                    && guess_validators::validate_guess_in_list(guess, &guesses_);
             })
         );
-        if response.cancel || response.quit {
-            return (WordlState::empty(), String::from(""), response.cancel, response.quit);
+        if response.cancel {
+            continue;
+        } else if response.quit {
+            return false;
+        }
+        history.push(WordlState::from(&response.state.to_lowercase(), secret));
+        after_guess(history);
+        println!("");
+        for state in history.iter() {
+            println!("  {}", display_state(state));
+        }
+    }
+    return true;
+}
+
+/// What the user chose to do in a turn of the interactive solver.
+enum TurnAction {
+    Guess(WordlState),
+    /// removes the last guess
+    Undo,
+    /// corrects the feedback of the guess with the given index
+    Edit(usize, WordlState),
+    Cancel,
+    Quit,
+}
+
+fn sub_menu_next_guess(config: &ConfigParams, history: &Vec<WordlState>, suggestion: &Option<String>) -> TurnAction {
+    // give user option to select top guess, or to correct previous guesses:
+    let re_yes = utils::construct_regex(r"^(1|y|yes|)$");
+    let re_no = utils::construct_regex(r"^(0|n|no)$");
+    let re_undo = utils::construct_regex(r"^(u|undo)$");
+    let re_edit = utils::construct_regex(r"^(e|edit)$");
+    let message = match suggestion {
+        Some(_) => "Choose the top suggestion as your next guess? (y/n, or undo/edit) >> ",
+        None => "Correct a previous guess? (undo/edit) >> ",
+    };
+    let response = cli::prompt::input(message, |text| {
+        let text = text.trim().to_lowercase();
+        return re_undo.is_match(&text) || re_edit.is_match(&text)
+            || suggestion.is_some() && (re_yes.is_match(&text) || re_no.is_match(&text));
+    });
+    if response.quit {
+        return TurnAction::Quit;
+    } else if response.cancel {
+        return TurnAction::Cancel;
+    }
+    let choice = response.state.trim().to_lowercase();
+    if re_undo.is_match(&choice) {
+        return TurnAction::Undo;
+    } else if re_edit.is_match(&choice) {
+        return sub_menu_edit_guess(config, history);
+    }
+    let guess = match suggestion {
+        Some(word) if re_yes.is_match(&choice) => word.clone(),
+        _ => {
+            let (guess, cancel, quit) = sub_menu_input_guess(config, history);
+            if quit {
+                return TurnAction::Quit;
+            } else if cancel {
+                return TurnAction::Cancel;
+            }
+            guess
+        },
+    };
+    let (feedback, cancel, quit) = sub_menu_feedback(config, &guess, "");
+    if quit {
+        return TurnAction::Quit;
+    } else if cancel {
+        return TurnAction::Cancel;
+    }
+    return TurnAction::Guess(WordlState::new(guess.as_str(), feedback.as_str()));
+}
+
+fn sub_menu_edit_guess(config: &ConfigParams, history: &Vec<WordlState>) -> TurnAction {
+    if history.len() == 0 {
+        println!("\nThere are no guesses to edit.");
+        return TurnAction::Cancel;
+    }
+    for (index, state) in history.iter().enumerate() {
+        println!("  {}. {}", index + 1, display_state(state));
+    }
+    let n = history.len();
+    let response = cli::prompt::input(
+        &format!("\nWhich guess has incorrect feedback? (1-{}) >> ", n),
+        // validator:
+        |text: &String| {
+            return text.trim().parse::<usize>().map(|index| 1 <= index && index <= n).unwrap_or(false);
         }
-        guess = response.state;
+    );
+    if response.quit {
+        return TurnAction::Quit;
+    } else if response.cancel {
+        return TurnAction::Cancel;
+    }
+    let index = response.state.trim().parse::<usize>().unwrap() - 1;
+    let guess = history[index].to_word();
+    let (feedback, cancel, quit) = sub_menu_feedback(config, &guess, " (corrected)");
+    if quit {
+        return TurnAction::Quit;
+    } else if cancel {
+        return TurnAction::Cancel;
     }
+    return TurnAction::Edit(index, WordlState::new(guess.as_str(), feedback.as_str()));
+}
+
+fn sub_menu_choose_guess(config: &ConfigParams, history: &Vec<WordlState>, guesses: &Vec<String>, suggestion: &Option<String>) -> (String, bool, bool) {
+    // give user option to select top guess (if there is one):
+    match suggestion {
+        Some(word) => {
+            let response = cli::prompt::confirm("Choose the top suggestion as your next guess? (y/n) >> ");
+            if response.cancel || response.quit {
+                return (String::from(""), response.cancel, response.quit);
+            }
+            if response.state {
+                return (word.clone(), false, false);
+            }
+        },
+        None => {
+            println!("\nThere is no suggestion, enter a guess of your own.");
+        },
+    }
+    // otherwise ask for input (which must be an allowed guess):
+    let guesses_ = guesses.clone();
+    let history_ = history.clone();
+    let response = cli::prompt::input(
+        "\nEnter your guess >> ",
+        // validator:
+        closure::closure!(move config, move history_, move guesses_, |guess: &String| {
+            return guess_validators::validate_guess(guess, &config, &history_)
+                && guess_validators::validate_guess_in_list(guess, &guesses_);
+        })
+    );
+    return (response.state.to_lowercase(), response.cancel, response.quit);
+}
 
+fn sub_menu_input_guess(config: &ConfigParams, history: &Vec<WordlState>) -> (String, bool, bool) {
+    let response = cli::prompt::input(
+        "\nEnter your guess >> ",
+        // validator:
+        closure::closure!(move config, move history, |guess: &String| {
+            return guess_validators::validate_guess(guess, &config, &history);
+        })
+    );
+    return (response.state, response.cancel, response.quit);
+}
+
+fn sub_menu_feedback(config: &ConfigParams, guess: &String, label: &str) -> (String, bool, bool) {
+    // example feedback for words of the length of the guess:
+    let example = patterns::example_feedback(utils::length_of_word(guess));
+    let symbols = example.chars()
+        .map(|a| match a {
+            '1' => "√",
+            '-' => "~",
+            _ => "x",
+        })
+        .collect::<Vec<&str>>()
+        .join("  ");
     let message = utils::dedent_ignore_first_last(
         "
 
-        Enter the feedback to your input \x1b[1m{}\x1b[0m
-          \x1b[2mE.g. if it was                  \x1b[2;1mx  x  ~  x  √\x1b[0m
-          \x1b[2mthen enter \x1b[4;1mxx-x1\x1b[0m\x1b[2m.\x1b[0m
+        Enter the feedback{} to your input \x1b[1m{}\x1b[0m
+          \x1b[2mE.g. if it was                  \x1b[2;1m{}\x1b[0m
+          \x1b[2mthen enter \x1b[4;1m{}\x1b[0m\x1b[2m.\x1b[0m
 
         {}"
     ).format(&[
-        display_word(&guess).as_str(),
+        label,
+        display_word(guess).as_str(),
+        symbols.as_str(),
+        example.as_str(),
         ">> ",
     ]);
 
@@ -225,10 +950,5 @@ fn sub_menu_next_guess(config: &ConfigParams, suggestion: &Option<String>) -> (W
             return guess_validators::validate_feedback(&guess_, feedback, config);
         })
     );
-    if response.cancel || response.quit {
-        return (WordlState::empty(), String::from(""), response.cancel, response.quit);
-    }
-    let feedback = response.state;
-
-    return (WordlState::new(guess.as_str(), feedback.as_str()), guess, false, false);
+    return (response.state, response.cancel, response.quit);
 }
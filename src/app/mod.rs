@@ -1,4 +1,16 @@
+pub mod absurdle;
+pub mod bench;
+pub mod book;
+pub mod daily;
+pub mod diagnosis;
+pub mod knowledge;
 pub mod menus;
+pub mod patterns;
+pub mod priors;
+pub mod session;
+pub mod solver;
 pub mod states;
+pub mod stats;
 pub mod tactics;
+pub mod tree;
 pub mod validators;
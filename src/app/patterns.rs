@@ -0,0 +1,422 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::io;
+use std::collections::HashMap;
+
+use crate::app::priors::Priors;
+use crate::core::console;
+use crate::core::utils;
+
+// ----------------------------------------------------------------
+// Structure Pattern
+// ----------------------------------------------------------------
+
+/// A feedback pattern encoded as a base-3 integer.
+///
+/// Each letter contributes one digit (`0` = incorrect, `1` = partially correct, `2` = correct),
+/// the first letter being the most significant digit.
+/// For words of length 5 there are thus 3^5 = 243 possible patterns.
+pub type Pattern = u32;
+
+/// Maximal length of words that can be encoded.
+pub const MAX_SIZE_OF_WORD: usize = 20;
+
+static CACHE_HEADER: &[u8] = b"WORDLE-FEEDBACK-MATRIX-01";
+
+/// Matrices with more entries than this (e.g. every word against every word) are not cached on disk.
+pub const MAX_CACHED_ENTRIES: usize = 64 * 1024 * 1024;
+
+// ----------------------------------------------------------------
+// Structure FeedbackMatrix
+// ----------------------------------------------------------------
+
+/// Precomputed feedback patterns of every guess against every solution.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::patterns::FeedbackMatrix;
+/// use wordle::app::patterns::compute_pattern;
+/// let words = vec![String::from("crane"), String::from("react"), String::from("nacre")];
+/// let matrix = FeedbackMatrix::new(&words, &words);
+/// assert_eq!(matrix.get("crane", "react"), compute_pattern("crane", "react"));
+/// assert_eq!(matrix.get("crane", "crane"), 242);
+/// // words outside of the lists are computed on the fly:
+/// assert_eq!(matrix.get("tract", "react"), compute_pattern("tract", "react"));
+/// ```
+pub struct FeedbackMatrix {
+    guesses: Vec<String>,
+    solutions: Vec<String>,
+    index_guesses: HashMap<String, usize>,
+    index_solutions: HashMap<String, usize>,
+    data: PatternStorage,
+    key: String,
+    priors: Priors,
+}
+
+enum PatternStorage {
+    Compact(Vec<u8>),
+    Wide(Vec<u32>),
+}
+
+// ----------------------------------------------------------------
+// Implementation FeedbackMatrix
+// ----------------------------------------------------------------
+
+impl FeedbackMatrix {
+    /// computes the full matrix of patterns for the given lists of guesses and solutions
+    pub fn new(guesses: &Vec<String>, solutions: &Vec<String>) -> Self {
+        let key = get_key(guesses, solutions);
+        let symbols_guesses = guesses.iter().map(|word| to_symbols(word)).collect::<Vec<Vec<char>>>();
+        let symbols_solutions = solutions.iter().map(|word| to_symbols(word)).collect::<Vec<Vec<char>>>();
+        let compact = is_compact(guesses, solutions);
+        let mut data_compact = Vec::<u8>::new();
+        let mut data_wide = Vec::<u32>::new();
+        for guess in symbols_guesses.iter() {
+            for solution in symbols_solutions.iter() {
+                let pattern = compute_pattern_from_symbols(guess, solution);
+                if compact {
+                    data_compact.push(pattern as u8);
+                } else {
+                    data_wide.push(pattern);
+                }
+            }
+        }
+        let data = if compact { PatternStorage::Compact(data_compact) } else { PatternStorage::Wide(data_wide) };
+        return FeedbackMatrix::from_parts(guesses, solutions, data, key);
+    }
+
+    /// loads the matrix from the cache in `path_to_directory` if present, otherwise computes and caches it
+    /// (matrices with more than `MAX_CACHED_ENTRIES` entries are only computed)
+    pub fn load_or_new(guesses: &Vec<String>, solutions: &Vec<String>, path_to_directory: &str) -> Self {
+        let size = guesses.len() * solutions.len();
+        if size > MAX_CACHED_ENTRIES {
+            eprintln!("{}", console::paint(&format!(
+                "[\x1b[93;1mWARNING\x1b[0m] The feedback matrix of {} guesses and {} solutions is too large to be cached and is computed anew (this may take a while).",
+                guesses.len(),
+                solutions.len(),
+            )));
+            return FeedbackMatrix::new(guesses, solutions);
+        }
+        let key = get_key(guesses, solutions);
+        let path = get_path_to_cache(path_to_directory, &key);
+        match read_cache(&path, size) {
+            Ok(data) => {
+                return FeedbackMatrix::from_parts(guesses, solutions, data, key);
+            },
+            Err(_) => { },
+        }
+        let matrix = FeedbackMatrix::new(guesses, solutions);
+        match matrix.write_cache(path_to_directory) {
+            Ok(_) => { },
+            Err(err) => {
+                eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Could not cache feedback matrix: {}", err)));
+            },
+        }
+        return matrix;
+    }
+
+    fn from_parts(guesses: &Vec<String>, solutions: &Vec<String>, data: PatternStorage, key: String) -> Self {
+        let index_guesses = guesses.iter()
+            .enumerate()
+            .map(|(index, word)| (word.clone(), index))
+            .collect::<HashMap<String, usize>>();
+        let index_solutions = solutions.iter()
+            .enumerate()
+            .map(|(index, word)| (word.clone(), index))
+            .collect::<HashMap<String, usize>>();
+        return FeedbackMatrix {
+            guesses: guesses.clone(),
+            solutions: solutions.clone(),
+            index_guesses,
+            index_solutions,
+            data,
+            key,
+            priors: Priors::uniform(),
+        };
+    }
+
+    /// weights the solutions by the priors (see `weighted_partition`)
+    pub fn with_priors(mut self: Self, priors: Priors) -> Self {
+        self.priors = priors;
+        return self;
+    }
+
+    pub fn priors<'life>(self: &'life Self) -> &'life Priors {
+        return &self.priors;
+    }
+
+    fn write_cache(self: &Self, path_to_directory: &str) -> Result<(), io::Error> {
+        std::fs::create_dir_all(path_to_directory)?;
+        let mut contents = CACHE_HEADER.to_vec();
+        match &self.data {
+            PatternStorage::Compact(data) => {
+                contents.push(1);
+                contents.extend(data.iter());
+            },
+            PatternStorage::Wide(data) => {
+                contents.push(4);
+                for pattern in data.iter() {
+                    contents.extend(pattern.to_le_bytes().iter());
+                }
+            },
+        }
+        return std::fs::write(get_path_to_cache(path_to_directory, &self.key), contents);
+    }
+
+    /// key identifying the lists of guesses and solutions
+    pub fn key(self: &Self) -> String {
+        return self.key.clone();
+    }
+
+    pub fn guesses<'life>(self: &'life Self) -> &'life Vec<String> {
+        return &self.guesses;
+    }
+
+    pub fn solutions<'life>(self: &'life Self) -> &'life Vec<String> {
+        return &self.solutions;
+    }
+
+    /// looks up the pattern of a guess against a solution (computes it, if either is not in the matrix)
+    pub fn get(self: &Self, guess: &str, solution: &str) -> Pattern {
+        match (self.index_guesses.get(guess), self.index_solutions.get(solution)) {
+            (Some(&i), Some(&j)) => {
+                let index = i * self.solutions.len() + j;
+                return match &self.data {
+                    PatternStorage::Compact(data) => data[index] as Pattern,
+                    PatternStorage::Wide(data) => data[index],
+                };
+            },
+            _ => {
+                return compute_pattern(guess, solution);
+            },
+        }
+    }
+
+    /// partitions the solutions by the pattern the guess would receive
+    pub fn partition(self: &Self, guess: &str, solutions: &Vec<String>) -> HashMap<Pattern, usize> {
+        let mut partition: HashMap<Pattern, usize> = HashMap::new();
+        for solution in solutions.iter() {
+            *partition.entry(self.get(guess, solution)).or_insert(0) += 1;
+        }
+        return partition;
+    }
+
+    /// as `partition`, but also sums the priors of the solutions producing each pattern
+    ///
+    /// ## Examples ##
+    ///
+    /// ```rust
+    /// use wordle::app::patterns::FeedbackMatrix;
+    /// use wordle::app::priors::Priors;
+    /// let words = vec![String::from("crane"), String::from("pilot"), String::from("tarse")];
+    /// let priors = Priors::from_lines(&vec![String::from("crane 3"), String::from("pilot 2"), String::from("tarse 1")]).unwrap();
+    /// let matrix = FeedbackMatrix::new(&words, &words).with_priors(priors);
+    /// let partition = matrix.weighted_partition("crane", &words);
+    /// assert_eq!(partition.get(&242), Some(&(1, 3.)));
+    /// assert_eq!(partition.values().map(|&(_, weight)| weight).sum::<f64>(), 6.);
+    /// ```
+    pub fn weighted_partition(self: &Self, guess: &str, solutions: &Vec<String>) -> HashMap<Pattern, (usize, f64)> {
+        let mut partition: HashMap<Pattern, (usize, f64)> = HashMap::new();
+        for solution in solutions.iter() {
+            let entry = partition.entry(self.get(guess, solution)).or_insert((0, 0.));
+            entry.0 += 1;
+            entry.1 += self.priors.weight(solution);
+        }
+        return partition;
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods patterns
+// ----------------------------------------------------------------
+
+/// computes the pattern a guess receives, given knowledge of the real word.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::patterns::compute_pattern;
+/// use wordle::app::patterns::pattern_to_feedback;
+/// assert_eq!(compute_pattern("alert", "alert"), 242);
+/// assert_eq!(compute_pattern("crane", "build"), 0);
+/// assert_eq!(pattern_to_feedback(compute_pattern("alert", "tread"), 5), "-x1--");
+/// assert_eq!(pattern_to_feedback(compute_pattern("speed", "abide"), 5), "xx-x-");
+/// ```
+pub fn compute_pattern(guess: &str, solution: &str) -> Pattern {
+    return compute_pattern_from_symbols(&to_symbols(guess), &to_symbols(solution));
+}
+
+/// encodes feedback strings (`1` = correct, `-` = partially correct, `0` / `x` = incorrect)
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::patterns::pattern_from_feedback;
+/// use wordle::app::patterns::pattern_to_feedback;
+/// assert_eq!(pattern_from_feedback("11111"), 242);
+/// assert_eq!(pattern_from_feedback("0x-x1"), 11);
+/// assert_eq!(pattern_to_feedback(11, 5), "xx-x1");
+/// ```
+pub fn pattern_from_feedback(feedback: &str) -> Pattern {
+    let mut pattern: Pattern = 0;
+    for a in feedback.chars() {
+        let digit = match a {
+            '1' => 2,
+            '-' => 1,
+            _ => 0,
+        };
+        pattern = 3 * pattern + digit;
+    }
+    return pattern;
+}
+
+/// decodes patterns to feedback strings, using the symbols `1`, `-` and `x`
+pub fn pattern_to_feedback(pattern: Pattern, size_of_word: usize) -> String {
+    let mut symbols = Vec::<&str>::new();
+    let mut rest = pattern;
+    for _ in 0..size_of_word {
+        symbols.push(match rest % 3 {
+            2 => "1",
+            1 => "-",
+            _ => "x",
+        });
+        rest /= 3;
+    }
+    symbols.reverse();
+    return symbols.join("");
+}
+
+/// an example of feedback for words of the given length (as shown in prompts),
+/// with one partially correct letter in the middle and the last letter correct.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::patterns::example_feedback;
+/// assert_eq!(example_feedback(4), "xx-1");
+/// assert_eq!(example_feedback(5), "xx-x1");
+/// assert_eq!(example_feedback(6), "xxx-x1");
+/// assert_eq!(example_feedback(7), "xxx-xx1");
+/// ```
+pub fn example_feedback(size_of_word: usize) -> String {
+    return (0..size_of_word)
+        .map(|index| {
+            if index + 1 == size_of_word {
+                return "1";
+            } else if index == size_of_word/2 {
+                return "-";
+            }
+            return "x";
+        })
+        .collect::<Vec<&str>>()
+        .join("");
+}
+
+/// the pattern of a fully correct guess
+pub fn pattern_correct(size_of_word: usize) -> Pattern {
+    return number_of_patterns(size_of_word) as Pattern - 1;
+}
+
+pub fn number_of_patterns(size_of_word: usize) -> usize {
+    return 3_usize.pow(size_of_word as u32);
+}
+
+/// Returns number of required changes and shifts encoded in a pattern (cf. `states::change_distance`).
+pub fn pattern_to_distance(pattern: Pattern, size_of_word: usize) -> (i32, i32) {
+    let mut nr_incorrect: i32 = 0;
+    let mut nr_shifts: i32 = 0;
+    let mut rest = pattern;
+    for _ in 0..size_of_word {
+        match rest % 3 {
+            0 => { nr_incorrect += 1; },
+            1 => { nr_shifts += 1; },
+            _ => { },
+        }
+        rest /= 3;
+    }
+    return (nr_incorrect, nr_shifts);
+}
+
+// ----------------------------------------------------------------
+// Auxiliary
+// ----------------------------------------------------------------
+
+fn to_symbols(word: &str) -> Vec<char> {
+    return word.chars()
+        .map(|a| a.to_ascii_uppercase())
+        .collect::<Vec<char>>();
+}
+
+fn compute_pattern_from_symbols(guess: &Vec<char>, solution: &Vec<char>) -> Pattern {
+    let n = guess.len();
+    assert_eq!(n, solution.len(), "Lengths of guess/real may not differ!");
+    assert!(n <= MAX_SIZE_OF_WORD, "Words may not be longer than {} letters!", MAX_SIZE_OF_WORD);
+    let mut digits = [0 as Pattern; MAX_SIZE_OF_WORD];
+    // marks letters of the solution, which have been matched:
+    let mut used = [false; MAX_SIZE_OF_WORD];
+    for index in 0..n {
+        if guess[index] == solution[index] {
+            digits[index] = 2;
+            used[index] = true;
+        }
+    }
+    for index in 0..n {
+        if digits[index] == 2 {
+            continue;
+        }
+        // check if guessed letter can still be used elsewhere in solution:
+        for j in 0..n {
+            if !used[j] && solution[j] == guess[index] {
+                used[j] = true;
+                digits[index] = 1;
+                break;
+            }
+        }
+    }
+    let mut pattern: Pattern = 0;
+    for index in 0..n {
+        pattern = 3 * pattern + digits[index];
+    }
+    return pattern;
+}
+
+fn is_compact(guesses: &Vec<String>, solutions: &Vec<String>) -> bool {
+    return guesses.iter()
+        .chain(solutions.iter())
+        .all(|word| number_of_patterns(utils::length_of_word(word)) <= 256);
+}
+
+fn get_key(guesses: &Vec<String>, solutions: &Vec<String>) -> String {
+    return format!("{}-{}", utils::hash_strings(guesses), utils::hash_strings(solutions));
+}
+
+fn get_path_to_cache(path_to_directory: &str, key: &String) -> String {
+    return format!("{}/feedback-{}.bin", path_to_directory.trim_end_matches("/"), key);
+}
+
+fn read_cache(path: &str, size: usize) -> Result<PatternStorage, io::Error> {
+    let contents = std::fs::read(path)?;
+    let n = CACHE_HEADER.len();
+    let invalid = io::Error::new(io::ErrorKind::InvalidData, "Cached feedback matrix is corrupt!");
+    if contents.len() <= n || !(&contents[..n] == CACHE_HEADER) {
+        return Err(invalid);
+    }
+    let data = &contents[(n + 1)..];
+    match contents[n] {
+        1 if data.len() == size => {
+            return Ok(PatternStorage::Compact(data.to_vec()));
+        },
+        4 if data.len() == 4 * size => {
+            let data = data.chunks(4)
+                .map(|chunk| Pattern::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect::<Vec<Pattern>>();
+            return Ok(PatternStorage::Wide(data));
+        },
+        _ => {
+            return Err(invalid);
+        },
+    }
+}
@@ -0,0 +1,140 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::io;
+
+use crate::core::utils;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+/// For lists ranked by frequency: the rank (as a fraction of the length of the list),
+/// at which the prior of a word has dropped to 1/2 ...
+static SIGMOID_MIDPOINT: f64 = 0.5;
+/// ... and the width (as a fraction of the length of the list) of the drop.
+static SIGMOID_WIDTH: f64 = 0.05;
+
+// ----------------------------------------------------------------
+// Structure Priors
+// ----------------------------------------------------------------
+
+/// Relative weights of words being the solution (all words weigh the same, if none are given).
+///
+/// A frequency file contains either one word and its count per line (e.g. `crane 1234`),
+/// in which case the weight of a word is its count,
+/// or one word per line ordered from most to least common,
+/// in which case the weight is a sigmoid of the rank.
+/// Words not listed get the smallest weight of the file.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::priors::Priors;
+/// let lines = vec![String::from("crane 30"), String::from("pilot,10")];
+/// let priors = Priors::from_lines(&lines).unwrap();
+/// let words = vec![String::from("crane"), String::from("pilot")];
+/// let probabilities = priors.probabilities(&words);
+/// assert_eq!(probabilities.get("crane"), Some(&0.75));
+/// assert_eq!(priors.weight("zzzzz"), 10.);
+///
+/// let lines = vec![String::from("crane"), String::from("pilot"), String::from("tarse")];
+/// let priors = Priors::from_lines(&lines).unwrap();
+/// assert!(priors.weight("crane") > priors.weight("pilot"));
+/// assert!(priors.weight("pilot") > priors.weight("tarse"));
+///
+/// assert!(Priors::uniform().is_uniform());
+/// assert!(Priors::from_lines(&vec![String::from("crane many")]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct Priors {
+    weights: HashMap<String, f64>,
+    default: f64,
+}
+
+// ----------------------------------------------------------------
+// Implementation Priors
+// ----------------------------------------------------------------
+
+impl Priors {
+    pub fn uniform() -> Self {
+        return Priors { weights: HashMap::new(), default: 1. };
+    }
+
+    /// reads the priors from a frequency file
+    pub fn from_path(path: &str) -> Result<Self, io::Error> {
+        let lines = utils::read_file_to_lines(path, true)?;
+        return Priors::from_lines(&lines)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{} in `{}`", err, path)));
+    }
+
+    /// reads the priors from the lines of a frequency file (see `Priors`)
+    pub fn from_lines(lines: &Vec<String>) -> Result<Self, String> {
+        let entries = lines.iter()
+            .map(|line| line.trim())
+            .filter(|line| !(*line == ""))
+            .map(|line| line.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|part| !(*part == ""))
+                .collect::<Vec<&str>>())
+            .collect::<Vec<Vec<&str>>>();
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        if entries.iter().all(|parts| parts.len() == 1) {
+            // ranked list:
+            let n = entries.len() as f64;
+            for (rank, parts) in entries.iter().enumerate() {
+                weights.entry(parts[0].to_lowercase()).or_insert(sigmoid(rank as f64, n));
+            }
+        } else {
+            // counts:
+            for parts in entries.iter() {
+                let count = match parts.as_slice() {
+                    [_, count] => count.parse::<f64>().ok().filter(|count| *count >= 0.),
+                    _ => None,
+                };
+                match count {
+                    Some(count) => { *weights.entry(parts[0].to_lowercase()).or_insert(0.) += count; },
+                    None => { return Err(format!("Expected `<word> <count>`, but found `{}`", parts.join(" "))); },
+                }
+            }
+        }
+        let default = weights.values().cloned().fold(f64::INFINITY, f64::min);
+        if !default.is_finite() {
+            return Ok(Priors::uniform());
+        }
+        return Ok(Priors { weights, default });
+    }
+
+    pub fn is_uniform(self: &Self) -> bool {
+        return self.weights.len() == 0;
+    }
+
+    /// the (unnormalised) weight of a word
+    pub fn weight(self: &Self, word: &str) -> f64 {
+        if self.is_uniform() {
+            return self.default;
+        }
+        return *self.weights.get(word).unwrap_or(&self.default);
+    }
+
+    /// the probability of each word being the solution, given that it is one of the words
+    pub fn probabilities(self: &Self, words: &Vec<String>) -> HashMap<String, f64> {
+        let total: f64 = words.iter().map(|word| self.weight(word)).sum();
+        return words.iter()
+            .map(|word| {
+                let p = if total > 0. { self.weight(word)/total } else { 1./(words.len() as f64) };
+                return (word.clone(), p);
+            })
+            .collect::<HashMap<String, f64>>();
+    }
+}
+
+// ----------------------------------------------------------------
+// Auxiliary methods
+// ----------------------------------------------------------------
+
+fn sigmoid(rank: f64, n: f64) -> f64 {
+    let x = (rank - SIGMOID_MIDPOINT * n)/(SIGMOID_WIDTH * n).max(1.);
+    return 1./(1. + x.exp());
+}
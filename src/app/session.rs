@@ -0,0 +1,107 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate yaml_rust;
+
+use std::io;
+use std::path::Path;
+
+use self::yaml_rust::Yaml;
+use self::yaml_rust::yaml::Array;
+use self::yaml_rust::yaml::Hash;
+
+use crate::app::patterns;
+use crate::app::solver;
+use crate::app::states::WordlState;
+use crate::app::tactics::selection::Tactic;
+use crate::core::utils;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+static FILENAME_SESSION: &str = "session.yml";
+
+// ----------------------------------------------------------------
+// Structure Session
+// ----------------------------------------------------------------
+
+/// An in-progress solve, saved after every turn, so that it can be resumed.
+pub struct Session {
+    /// key identifying the lists of guesses and solutions (see `FeedbackMatrix::key`)
+    pub key: String,
+    pub tactic: Tactic,
+    pub history: Vec<WordlState>,
+}
+
+// ----------------------------------------------------------------
+// Implementation Session
+// ----------------------------------------------------------------
+
+impl Session {
+    /// whether a session is saved in the data directory
+    pub fn exists(path_to_directory: &str) -> bool {
+        return Path::new(&get_path_to_session(path_to_directory)).exists();
+    }
+
+    /// loads the session from the data directory (`None` if there is none)
+    ///
+    /// NOTE: The guesses are parsed with the word length of the session,
+    /// so that a session for other word lists can still be recognised (by its key).
+    pub fn load(path_to_directory: &str) -> Result<Option<Self>, String> {
+        let path = get_path_to_session(path_to_directory);
+        if !Path::new(&path).exists() {
+            return Ok(None);
+        }
+        let spec = utils::read_yaml(&path)
+            .map_err(|err| format!("Could not read session `{}`: {}", path, err))?;
+        let invalid = || format!("Session `{}` is malformed.", path);
+        let key = spec["key"].as_str().ok_or_else(invalid)?.to_string();
+        let tactic = spec["tactic"].as_str().and_then(Tactic::from_name).ok_or_else(invalid)?;
+        let entries = spec["history"].as_vec().ok_or_else(invalid)?
+            .iter()
+            .map(|entry| entry.as_str().map(|entry| entry.to_string()).ok_or_else(invalid))
+            .collect::<Result<Vec<String>, String>>()?;
+        let size_of_wordle = entries.get(0)
+            .map(|entry| utils::length_of_word(&entry.split(":").next().unwrap_or("").to_string()))
+            .unwrap_or(0);
+        // rebuilds the states via `WordlState::new`:
+        let history = solver::parse_history(&entries, size_of_wordle)
+            .map_err(|err| format!("Session `{}` is malformed: {}", path, err))?;
+        return Ok(Some(Session { key, tactic, history }));
+    }
+
+    /// writes the session to the data directory, with guesses stored as `guess:feedback`
+    pub fn save(self: &Self, path_to_directory: &str) -> Result<(), io::Error> {
+        std::fs::create_dir_all(path_to_directory)?;
+        let entries = self.history.iter()
+            .map(|state| {
+                let feedback = patterns::pattern_to_feedback(state.to_pattern(), state.len());
+                return Yaml::String(format!("{}:{}", state.to_word(), feedback));
+            })
+            .collect::<Array>();
+        let mut spec = Hash::new();
+        spec.insert(Yaml::String(String::from("key")), Yaml::String(self.key.clone()));
+        spec.insert(Yaml::String(String::from("tactic")), Yaml::String(self.tactic.name().to_string()));
+        spec.insert(Yaml::String(String::from("history")), Yaml::Array(entries));
+        return utils::write_yaml(&get_path_to_session(path_to_directory), &Yaml::Hash(spec));
+    }
+
+    /// removes the session from the data directory (once the game is over)
+    pub fn clear(path_to_directory: &str) -> Result<(), io::Error> {
+        let path = get_path_to_session(path_to_directory);
+        if Path::new(&path).exists() {
+            std::fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+}
+
+// ----------------------------------------------------------------
+// Auxiliary methods
+// ----------------------------------------------------------------
+
+fn get_path_to_session(path_to_directory: &str) -> String {
+    return Path::new(path_to_directory).join(FILENAME_SESSION).to_string_lossy().to_string();
+}
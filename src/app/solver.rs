@@ -0,0 +1,236 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::app::book::OpeningBook;
+use crate::app::knowledge::Knowledge;
+use crate::app::patterns;
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::priors::Priors;
+use crate::app::states::WordlState;
+use crate::app::tactics;
+use crate::app::tactics::selection::Tactic;
+use crate::core::utils;
+use crate::setup::config::ConfigParams;
+
+// ----------------------------------------------------------------
+// Methods - feedback matrix
+// ----------------------------------------------------------------
+
+/// computes the feedback matrix of the word lists (or loads it from the cache, if configured),
+/// weighting the solutions by the priors (see `Priors::uniform` for none)
+pub fn get_feedback_matrix(config: &ConfigParams, guesses: &Vec<String>, solutions: &Vec<String>, priors: &Priors) -> FeedbackMatrix {
+    let matrix = if config.cache_feedback_matrix {
+        FeedbackMatrix::load_or_new(guesses, solutions, &config.data_directory)
+    } else {
+        FeedbackMatrix::new(guesses, solutions)
+    };
+    return matrix.with_priors(priors.clone());
+}
+
+/// loads (or computes) the opening book for the word lists and tactic, if configured
+/// (the book assumes all solutions to be equally likely, so is not used with frequencies)
+pub fn get_opening_book(config: &ConfigParams, matrix: &FeedbackMatrix) -> Option<OpeningBook> {
+    if config.opening_book && matrix.priors().is_uniform() {
+        return Some(OpeningBook::load_or_new(config, matrix));
+    }
+    return None;
+}
+
+// ----------------------------------------------------------------
+// Methods - suggestions
+// ----------------------------------------------------------------
+
+/// restricts guesses to those, which use every hint revealed so far
+pub fn get_hard_mode_guesses(guesses: &Vec<String>, history: &Vec<WordlState>) -> Vec<String> {
+    return guesses.iter()
+        .filter(|&guess| history.iter().all(|state| state.hard_mode_violation(guess).is_none()))
+        .cloned()
+        .collect::<Vec<String>>();
+}
+
+/// ranks the permitted guesses, best first
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (tactic, hard mode, limits)
+/// - `matrix` - precomputed feedback patterns (contains the list of allowed guesses)
+/// - `history` - states of previous guesses
+/// - `words_remaining` - list of remaining possible solutions
+///
+/// ## Returns ##
+///
+/// The sorted list of suggestions. If the list of remaining words is too long
+/// for the configured tactic, only remaining words are suggested.
+pub fn get_suggestions(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    history: &Vec<WordlState>,
+    words_remaining: &Vec<String>,
+) -> Vec<String> {
+    let mut suggestions: Vec<String>;
+    if words_remaining.len() <= config.max_length_for_best_optimisation {
+        // in hard mode only guesses using all revealed hints are permitted:
+        suggestions = if config.hard_mode {
+            get_hard_mode_guesses(matrix.guesses(), history)
+        } else {
+            matrix.guesses().clone()
+        };
+        tactics::selection::reduce_sort_by_tactic(&config.tactic, matrix, &mut suggestions, words_remaining);
+    } else {
+        suggestions = words_remaining.clone();
+        tactics::basic::reduce_sort_by_entropy_then_uniqueness(&mut suggestions);
+        if words_remaining.len() > config.max_display_length {
+            let words_unique = tactics::basic::reduce_to_words_with_unique_letters(&suggestions);
+            if words_unique.len() > 0 {
+                suggestions = words_unique;
+            }
+        }
+    }
+    return suggestions;
+}
+
+/// ranks the guesses for several boards at once (as in Dordle, Quordle, Octordle),
+/// by the information combined across the unsolved boards.
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (limits)
+/// - `matrix` - precomputed feedback patterns (contains the list of allowed guesses)
+/// - `boards` - lists of remaining possible solutions of the unsolved boards
+///
+/// ## Returns ##
+///
+/// The sorted list of suggestions. If the lists of remaining words are too long,
+/// only remaining words are suggested.
+pub fn get_suggestions_for_boards(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    boards: &Vec<Vec<String>>,
+) -> Vec<String> {
+    let n: usize = boards.iter().map(|words| words.len()).sum();
+    let mut suggestions: Vec<String> = if n <= config.max_length_for_best_optimisation {
+        matrix.guesses().clone()
+    } else {
+        let mut words = boards.iter().flatten().cloned().collect::<Vec<String>>();
+        words.sort();
+        words.dedup();
+        words
+    };
+    tactics::advanced::reduce_sort_by_combined_information_then_uniqueness(matrix, &mut suggestions, boards);
+    return suggestions;
+}
+
+/// as `get_suggestions`, but uses the opening book for the first two guesses
+pub fn get_suggestions_with_book(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    book: &Option<OpeningBook>,
+    history: &Vec<WordlState>,
+    words_remaining: &Vec<String>,
+) -> Vec<String> {
+    match book.as_ref().and_then(|book| book.lookup(history)) {
+        Some(word) => {
+            return vec![word];
+        },
+        None => {
+            return get_suggestions(config, matrix, history, words_remaining);
+        },
+    }
+}
+
+/// computes the scores of suggestions (as ranked by `get_suggestions`)
+///
+/// ## Returns ##
+///
+/// `(tactic, scores)`, i.e. the tactic actually used for ranking and the scores per suggestion.
+pub fn get_scores(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    suggestions: &Vec<String>,
+    words_remaining: &Vec<String>,
+) -> (Tactic, HashMap<String, f64>) {
+    let tactic = if words_remaining.len() <= config.max_length_for_best_optimisation {
+        config.tactic
+    } else {
+        Tactic::Entropy
+    };
+    let scores = tactics::selection::get_scores_by_tactic(&tactic, matrix, suggestions, words_remaining);
+    return (tactic, scores);
+}
+
+// ----------------------------------------------------------------
+// Methods - history
+// ----------------------------------------------------------------
+
+/// narrows down the possible solutions by every state in the history,
+/// checking each word once against the knowledge merged from all turns.
+///
+/// ## Examples ##
+///
+/// The result agrees with narrowing down turn by turn:
+///
+/// ```rust
+/// use wordle::app::patterns::FeedbackMatrix;
+/// use wordle::app::solver::get_words_remaining;
+/// use wordle::app::states::WordlState;
+/// use wordle::setup::assets::get_solutions;
+/// let solutions = get_solutions().unwrap();
+/// let matrix = FeedbackMatrix::new(&solutions, &solutions);
+/// for solution in ["abbey", "ferry", "spell", "eerie", "tiger"].iter() {
+///     let history = ["crane", "speed", "geese", "pilot"].iter()
+///         .map(|guess| WordlState::from(guess, solution))
+///         .collect::<Vec<WordlState>>();
+///     let mut words_remaining = solutions.clone();
+///     for state in history.iter() {
+///         words_remaining = state.constrain_with_matrix(&matrix, &words_remaining);
+///     }
+///     assert_eq!(get_words_remaining(&solutions, &history), words_remaining);
+/// }
+///
+/// // also if the feedback contradicts itself (two different letters correct at the same position):
+/// let history = vec![WordlState::new("crane", "1xxxx"), WordlState::new("brine", "1xxxx")];
+/// let mut words_remaining = solutions.clone();
+/// for state in history.iter() {
+///     words_remaining = state.constrain_with_matrix(&matrix, &words_remaining);
+/// }
+/// assert_eq!(words_remaining.len(), 0);
+/// assert_eq!(get_words_remaining(&solutions, &history), words_remaining);
+/// ```
+pub fn get_words_remaining(solutions: &Vec<String>, history: &Vec<WordlState>) -> Vec<String> {
+    return Knowledge::from_history(history).constrain(solutions);
+}
+
+/// reads states from pairs of the form `guess:feedback`, e.g. `crane:xx-x1`
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::solver::parse_history;
+/// let entries = vec![String::from("crane:xx-x1"), String::from("PILOT:0-0x1")];
+/// let history = parse_history(&entries, 5).unwrap();
+/// assert_eq!(history[1].to_word(), "pilot");
+/// assert!(parse_history(&vec![String::from("crane:xx-x")], 5).is_err());
+/// assert!(parse_history(&vec![String::from("crane")], 5).is_err());
+/// ```
+pub fn parse_history(entries: &Vec<String>, size_of_wordle: usize) -> Result<Vec<WordlState>, String> {
+    let re = utils::construct_regex(r"^(\w+):([01x-]+)$");
+    let mut history = Vec::<WordlState>::new();
+    for entry in entries.iter() {
+        let entry = entry.trim().to_lowercase();
+        let captures = re.captures(&entry)
+            .ok_or_else(|| format!("`{}` must be of the form `guess:feedback`, e.g. `<guess>:{}`.", entry, patterns::example_feedback(size_of_wordle)))?;
+        let guess = captures[1].to_string();
+        let feedback = captures[2].to_string();
+        if !(utils::length_of_word(&guess) == size_of_wordle) {
+            return Err(format!("Length of guess `{}` must be {}.", guess, size_of_wordle));
+        }
+        if !(utils::length_of_word(&feedback) == size_of_wordle) {
+            return Err(format!("Length of feedback `{}` must match length of guess `{}`, e.g. `{}`.", feedback, guess, patterns::example_feedback(size_of_wordle)));
+        }
+        history.push(WordlState::new(&guess, &feedback));
+    }
+    return Ok(history);
+}
@@ -8,11 +8,16 @@ use std::collections::HashMap;
 use self::dyn_fmt::AsStrFormatExt;
 
 use crate::core::utils;
+use crate::app::patterns;
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
+use crate::app::knowledge::Knowledge;
 
 // ----------------------------------------------------------------
 // Structure WordlState
 // ----------------------------------------------------------------
 
+#[derive(Clone)]
 pub struct WordlState {
     pub states: Vec<WordlCharState>,
     pub constraints: Vec<WordlConstraint>,
@@ -21,6 +26,7 @@ pub struct WordlState {
     repr_feedback_anon: String,
 }
 
+#[derive(Clone)]
 pub struct WordlCharState {
     pub symbol: String,
     pub correct: bool,
@@ -30,12 +36,13 @@ pub struct WordlCharState {
     repr_feedback_anon: String,
 }
 
+#[derive(Clone)]
 pub struct WordlConstraint {
-    symbol: String,
-    indexes: Vec<usize>,
-    non_indexes: Vec<usize>,
-    contains_min: i32,
-    bounded: bool,
+    pub symbol: String,
+    pub indexes: Vec<usize>,
+    pub non_indexes: Vec<usize>,
+    pub contains_min: i32,
+    pub bounded: bool,
 }
 
 // ----------------------------------------------------------------
@@ -218,7 +225,8 @@ impl WordlState {
             }
             states.push(WordlCharState::new(&a, correct, partial));
         }
-        let constraints: Vec<WordlConstraint> = constraints_map.into_values().collect();
+        let mut constraints: Vec<WordlConstraint> = constraints_map.into_values().collect();
+        constraints.sort_by(|c1, c2| c1.symbol.cmp(&c2.symbol));
         let mut states = WordlState {
             states,
             constraints,
@@ -237,7 +245,7 @@ impl WordlState {
         return compare_guess(guess, solution);
     }
 
-    pub fn is_correct(self: Self) -> bool {
+    pub fn is_correct(self: &Self) -> bool {
         for state in self.states.iter() {
             if !state.correct || state.partial { return false; }
         }
@@ -253,6 +261,40 @@ impl WordlState {
         return true;
     }
 
+    /// checks whether a guess uses every hint revealed in this state, as required in hard mode.
+    ///
+    /// ## Returns ##
+    ///
+    /// `None` if the guess is permitted, otherwise a description of the first rule broken.
+    ///
+    /// ## Examples ##
+    ///
+    /// ```rust
+    /// use wordle::app::states::WordlState;
+    /// let state = WordlState::new("crane", "x-xx1");
+    /// assert_eq!(state.hard_mode_violation(&String::from("rouse")), None);
+    /// assert_eq!(state.hard_mode_violation(&String::from("roust")), Some(String::from("5th letter must be E")));
+    /// assert_eq!(state.hard_mode_violation(&String::from("olive")), Some(String::from("guess must contain R")));
+    /// ```
+    pub fn hard_mode_violation(self: &Self, guess: &String) -> Option<String> {
+        let chars = utils::chars_to_uppercase(utils::string_to_chars(guess));
+        for (index, state) in self.states.iter().enumerate() {
+            if state.correct && !state.partial && chars.get(index) != Some(&state.symbol) {
+                return Some(format!("{} letter must be {}", utils::ordinal(index + 1), state.symbol));
+            }
+        }
+        for constraint in self.constraints.iter() {
+            let count = chars.iter().filter(|&a| a == &constraint.symbol).count() as i32;
+            if count < constraint.contains_min {
+                if constraint.contains_min == 1 {
+                    return Some(format!("guess must contain {}", constraint.symbol));
+                }
+                return Some(format!("guess must contain {} at least {} times", constraint.symbol, constraint.contains_min));
+            }
+        }
+        return None;
+    }
+
     /// reduces list of possible next words based on information contained in feedback.
     pub fn constrain(self: &Self, words: &Vec<String>) -> Vec<String> {
         return words.iter()
@@ -261,6 +303,34 @@ impl WordlState {
             .collect::<Vec<String>>();
     }
 
+    /// reduces list of possible next words by looking up patterns instead of checking constraints.
+    pub fn constrain_with_matrix(self: &Self, matrix: &FeedbackMatrix, words: &Vec<String>) -> Vec<String> {
+        let guess = self.to_word();
+        let pattern = self.to_pattern();
+        return words.iter()
+            .cloned()
+            .filter(|word| matrix.get(&guess, word) == pattern)
+            .collect::<Vec<String>>();
+    }
+
+    /// the guessed word (in lower case).
+    pub fn to_word(self: &Self) -> String {
+        return self.states.iter()
+            .map(|state| state.symbol.to_ascii_lowercase())
+            .collect::<Vec<String>>()
+            .join("");
+    }
+
+    /// the feedback encoded as a pattern (see `patterns::Pattern`).
+    pub fn to_pattern(self: &Self) -> Pattern {
+        let mut pattern: Pattern = 0;
+        for state in self.states.iter() {
+            let digit = if !state.correct { 0 } else if state.partial { 1 } else { 2 };
+            pattern = 3 * pattern + digit;
+        }
+        return pattern;
+    }
+
     fn to_representation(self: &Self, with_feedback: bool, anon: bool) -> String {
         return self.states.iter()
             .map(
@@ -307,6 +377,34 @@ impl WordlState {
     }
 }
 
+// ----------------------------------------------------------------
+// Structure Board
+// ----------------------------------------------------------------
+
+/// One of several boards sharing each guess (as in Dordle, Quordle, Octordle).
+pub struct Board {
+    pub history: Vec<WordlState>,
+    pub knowledge: Knowledge,
+    pub words_remaining: Vec<String>,
+}
+
+impl Board {
+    pub fn new(words: &Vec<String>) -> Self {
+        return Board { history: vec![], knowledge: Knowledge::new(), words_remaining: words.clone() };
+    }
+
+    pub fn is_solved(self: &Self) -> bool {
+        return self.history.last().map(|state| state.is_correct()).unwrap_or(false);
+    }
+
+    /// records the feedback of a guess and narrows down the remaining words accordingly.
+    pub fn update(self: &mut Self, state: WordlState) {
+        self.knowledge.add(&state);
+        self.words_remaining = self.knowledge.constrain(&self.words_remaining);
+        self.history.push(state);
+    }
+}
+
 // ----------------------------------------------------------------
 // Auxiliary
 // ----------------------------------------------------------------
@@ -374,17 +472,6 @@ fn compare_guess(guess: &str, solution: &str) -> WordlState {
 /// assert_eq!(change_distance("tor", "dot"), (1, 1));
 /// ```
 pub fn change_distance(guess: &str, solution: &str) -> (i32, i32) {
-    let wordl_state = WordlState::from(guess, solution);
-    let mut nr_incorrect: i32 = 0; // counts number of required changes
-    let mut nr_shifts: i32 = 0;    // counts number of required shifts
-    for state in wordl_state.states.iter() {
-        if state.correct {
-            if state.partial {
-                nr_shifts += 1;
-            }
-        } else {
-            nr_incorrect += 1;
-        }
-    }
-    return (nr_incorrect, nr_shifts);
+    let pattern = patterns::compute_pattern(guess, solution);
+    return patterns::pattern_to_distance(pattern, utils::length_of_word(&guess.to_string()));
 }
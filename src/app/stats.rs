@@ -0,0 +1,179 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate yaml_rust;
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use self::yaml_rust::Yaml;
+use self::yaml_rust::yaml::Hash;
+
+use crate::core::console;
+use crate::core::utils;
+use crate::setup::config::ConfigParams;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+static FILENAME_STATISTICS: &str = "stats.yml";
+
+/// Kinds of games recorded: assisted solving, playing a hidden word and the daily puzzle.
+pub static MODES: &[&str] = &["solve", "play", "daily"];
+
+// ----------------------------------------------------------------
+// Structure GameStatistics
+// ----------------------------------------------------------------
+
+/// Statistics of all games of one kind.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::stats::GameStatistics;
+/// let mut stats = GameStatistics::new();
+/// stats.record(Some(4));
+/// stats.record(Some(3));
+/// stats.record(None);
+/// stats.record(Some(4));
+/// assert_eq!(stats.played, 4);
+/// assert_eq!(stats.current_streak, 1);
+/// assert_eq!(stats.max_streak, 2);
+/// assert_eq!(stats.distribution.get(&4), Some(&2));
+/// assert_eq!(stats.win_percentage(), 75.);
+/// ```
+pub struct GameStatistics {
+    pub played: usize,
+    pub won: usize,
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// number of games won per number of guesses needed
+    pub distribution: BTreeMap<usize, usize>,
+}
+
+// ----------------------------------------------------------------
+// Implementation GameStatistics
+// ----------------------------------------------------------------
+
+impl GameStatistics {
+    pub fn new() -> Self {
+        return GameStatistics {
+            played: 0,
+            won: 0,
+            current_streak: 0,
+            max_streak: 0,
+            distribution: BTreeMap::new(),
+        };
+    }
+
+    /// records a game, which was won with the given number of guesses (`None` if lost)
+    pub fn record(self: &mut Self, guesses: Option<usize>) {
+        self.played += 1;
+        match guesses {
+            Some(n) => {
+                self.won += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                *self.distribution.entry(n).or_insert(0) += 1;
+            },
+            None => {
+                self.current_streak = 0;
+            },
+        }
+    }
+
+    pub fn win_percentage(self: &Self) -> f64 {
+        if self.played == 0 {
+            return 0.;
+        }
+        return 100. * (self.won as f64)/(self.played as f64);
+    }
+
+    fn from_yaml(spec: &Yaml) -> Option<Self> {
+        let mut distribution: BTreeMap<usize, usize> = BTreeMap::new();
+        for (n, count) in spec["distribution"].as_hash()?.iter() {
+            distribution.insert(n.as_i64()? as usize, count.as_i64()? as usize);
+        }
+        return Some(GameStatistics {
+            played: spec["played"].as_i64()? as usize,
+            won: spec["won"].as_i64()? as usize,
+            current_streak: spec["current-streak"].as_i64()? as usize,
+            max_streak: spec["max-streak"].as_i64()? as usize,
+            distribution,
+        });
+    }
+
+    fn to_yaml(self: &Self) -> Yaml {
+        let mut distribution = Hash::new();
+        for (&n, &count) in self.distribution.iter() {
+            distribution.insert(Yaml::Integer(n as i64), Yaml::Integer(count as i64));
+        }
+        let mut spec = Hash::new();
+        spec.insert(Yaml::String(String::from("played")), Yaml::Integer(self.played as i64));
+        spec.insert(Yaml::String(String::from("won")), Yaml::Integer(self.won as i64));
+        spec.insert(Yaml::String(String::from("current-streak")), Yaml::Integer(self.current_streak as i64));
+        spec.insert(Yaml::String(String::from("max-streak")), Yaml::Integer(self.max_streak as i64));
+        spec.insert(Yaml::String(String::from("distribution")), Yaml::Hash(distribution));
+        return Yaml::Hash(spec);
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods
+// ----------------------------------------------------------------
+
+pub fn get_path_to_statistics(path_to_directory: &str) -> String {
+    return Path::new(path_to_directory).join(FILENAME_STATISTICS).to_string_lossy().to_string();
+}
+
+/// reads the statistics, keyed by kind of game (empty, if nothing was recorded yet)
+pub fn read_statistics(path: &str) -> Result<BTreeMap<String, GameStatistics>, io::Error> {
+    let mut statistics: BTreeMap<String, GameStatistics> = BTreeMap::new();
+    if !Path::new(path).exists() {
+        return Ok(statistics);
+    }
+    let spec = utils::read_yaml(path)?;
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Statistics are malformed!");
+    for (mode, entry) in spec.as_hash().ok_or_else(invalid)?.iter() {
+        let mode = mode.as_str().ok_or_else(invalid)?.to_string();
+        statistics.insert(mode, GameStatistics::from_yaml(entry).ok_or_else(invalid)?);
+    }
+    return Ok(statistics);
+}
+
+pub fn write_statistics(path: &str, statistics: &BTreeMap<String, GameStatistics>) -> Result<(), io::Error> {
+    match Path::new(path).parent() {
+        Some(directory) => { std::fs::create_dir_all(directory)?; },
+        None => { },
+    }
+    let mut spec = Hash::new();
+    for (mode, entry) in statistics.iter() {
+        spec.insert(Yaml::String(mode.clone()), entry.to_yaml());
+    }
+    return utils::write_yaml(path, &Yaml::Hash(spec));
+}
+
+/// records the end of a game in the statistics file of the data directory
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (data directory)
+/// - `mode` - kind of game (see `MODES`)
+/// - `guesses` - number of guesses needed, if the game was won (`None` if lost)
+pub fn record_game(config: &ConfigParams, mode: &str, guesses: Option<usize>) {
+    let path = get_path_to_statistics(&config.data_directory);
+    let result = read_statistics(&path)
+        .and_then(|mut statistics| {
+            statistics.entry(mode.to_string()).or_insert(GameStatistics::new()).record(guesses);
+            return write_statistics(&path, &statistics);
+        });
+    match result {
+        Ok(_) => { },
+        Err(err) => {
+            eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Could not record statistics in `{}`: {}", path, err)));
+        },
+    }
+}
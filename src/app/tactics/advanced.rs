@@ -5,65 +5,81 @@
 extern crate mint;
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
-use crate::app::states::WordlState;
-use crate::app::states::change_distance;
+use crate::app::patterns;
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
 use crate::core::utils;
 use crate::core::comparison;
 
-use super::basic::get_entropy;
+use super::basic::get_entropy_of_guesses;
+
+// ----------------------------------------------------------------
+// NOTE
+// ----------------------------------------------------------------
+
+// The tactics below sort a list of `guesses` by how well they narrow down
+// the list `words` of remaining possible solutions.
+// Guesses need not be possible solutions themselves,
+// but in case of ties possible solutions are preferred.
 
 // ----------------------------------------------------------------
 // Tactic sort by potential remaining size
 // ----------------------------------------------------------------
 
-fn get_average_size_of_remaining_words(words: &Vec<String>) -> HashMap<String, f64> {
+/// computes for each guess the expected number of remaining words,
+/// where the solutions are weighted by the priors of the matrix
+pub fn get_average_size_of_remaining_words(matrix: &FeedbackMatrix, guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, f64> {
     let mut sizes: HashMap<String, f64> = HashMap::new();
-    let n = words.len();
-    for (_, guess) in words.iter().enumerate() {
-        let mut count = 0;
-        for (_, solution) in words.iter().enumerate() {
-            let state = WordlState::from(guess, solution);
-            let word_remaining = state.constrain(words);
-            count += word_remaining.len();
-        }
-        let p: f64 = (count as f64)/(n as f64);
+    for (_, guess) in guesses.iter().enumerate() {
+        // each solution leaves exactly the words in its own bucket:
+        let partition = matrix.weighted_partition(guess, words);
+        let total: f64 = partition.values().map(|&(_, weight)| weight).sum();
+        let count: f64 = partition.values().map(|&(size, weight)| (size as f64) * weight).sum();
+        let p: f64 = if total > 0. { count/total } else { 0. };
         sizes.insert(guess.clone(), p);
     }
     return sizes;
 }
 
-pub fn reduce_sort_by_remaining_size_then_entropy_then_uniqueness(words: &mut Vec<String>) {
-    let sizes = get_average_size_of_remaining_words(&*words);
-    let entropy = get_entropy(&*words);
+pub fn reduce_sort_by_remaining_size_then_entropy_then_uniqueness(matrix: &FeedbackMatrix, guesses: &mut Vec<String>, words: &Vec<String>) {
+    let sizes = get_average_size_of_remaining_words(matrix, &*guesses, words);
+    let entropy = get_entropy_of_guesses(&*guesses, words);
+    let candidates = words.iter().collect::<HashSet<&String>>();
     let cmp = |u1: &String, u2: &String| {
         let s1 = *sizes.get(u1).unwrap();
-        let s2 = *sizes.get(u1).unwrap();
+        let s2 = *sizes.get(u2).unwrap();
+        let c1 = candidates.contains(u1);
+        let c2 = candidates.contains(u2);
         let h1 = *entropy.get(u1).unwrap();
         let h2 = *entropy.get(u2).unwrap();
         let n1 = utils::nr_unique_letters(u1);
         let n2 = utils::nr_unique_letters(u2); // sort highest first
         return comparison::lexical_comparison(&vec![
             comparison::cmp_type::<f64>(s1, s2),   // sort lowest first
+            comparison::cmp_type::<bool>(c2, c1),  // sort possible solutions first
             comparison::cmp_type::<f64>(h2, h1),   // sort highest first
             comparison::cmp_type::<usize>(n2, n1), // sort highest first
         ]);
     };
-    words.sort_by(cmp);
+    guesses.sort_by(cmp);
 }
 
 // ----------------------------------------------------------------
 // Tactic sort by average distance to other words
 // ----------------------------------------------------------------
 
-fn get_distances(words: &Vec<String>) -> HashMap<String, (f64, f64)> {
+pub fn get_distances(matrix: &FeedbackMatrix, guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, (f64, f64)> {
     let mut dist: HashMap<String, (f64, f64)> = HashMap::new();
     let n = words.len();
-    for (_, guess) in words.iter().enumerate() {
+    for (_, guess) in guesses.iter().enumerate() {
         let mut count1 = 0;
         let mut count2 = 0;
+        let size_of_word = utils::length_of_word(guess);
         for (_, solution) in words.iter().enumerate() {
-            let (d1, d2) = change_distance(guess.as_str(), solution.as_str());
+            let pattern = matrix.get(guess.as_str(), solution.as_str());
+            let (d1, d2) = patterns::pattern_to_distance(pattern, size_of_word);
             count1 += d1;
             count2 += d2;
         }
@@ -74,12 +90,15 @@ fn get_distances(words: &Vec<String>) -> HashMap<String, (f64, f64)> {
     return dist;
 }
 
-pub fn reduce_sort_by_distance_then_entropy_then_uniqueness(words: &mut Vec<String>) {
-    let dist = get_distances(&*words);
-    let entropy = get_entropy(&*words);
+pub fn reduce_sort_by_distance_then_entropy_then_uniqueness(matrix: &FeedbackMatrix, guesses: &mut Vec<String>, words: &Vec<String>) {
+    let dist = get_distances(matrix, &*guesses, words);
+    let entropy = get_entropy_of_guesses(&*guesses, words);
+    let candidates = words.iter().collect::<HashSet<&String>>();
     let cmp = |u1: &String, u2: &String| {
         let (n_incorrect1, n_shift1) = *dist.get(u1).unwrap();
-        let (n_incorrect2, n_shift2) = *dist.get(u1).unwrap();
+        let (n_incorrect2, n_shift2) = *dist.get(u2).unwrap();
+        let c1 = candidates.contains(u1);
+        let c2 = candidates.contains(u2);
         let h1 = *entropy.get(u1).unwrap();
         let h2 = *entropy.get(u2).unwrap();
         let n1 = utils::nr_unique_letters(u1);
@@ -87,9 +106,154 @@ pub fn reduce_sort_by_distance_then_entropy_then_uniqueness(words: &mut Vec<Stri
         return comparison::lexical_comparison(&vec![
             comparison::cmp_type::<f64>(n_incorrect1, n_incorrect2), // sort lowest first
             comparison::cmp_type::<f64>(n_shift1, n_shift2), // sort lowest first
+            comparison::cmp_type::<bool>(c2, c1),  // sort possible solutions first
+            comparison::cmp_type::<f64>(h2, h1),   // sort highest first
+            comparison::cmp_type::<usize>(n2, n1), // sort highest first
+        ]);
+    };
+    guesses.sort_by(cmp);
+}
+
+// ----------------------------------------------------------------
+// Tactic sort by information (entropy of feedback partitions)
+// ----------------------------------------------------------------
+
+/// partitions list of words by the feedback a guess would receive, were the word the solution
+///
+/// ## Arguments ##
+///
+/// - `matrix` - precomputed feedback patterns
+/// - `guess` - the word to be played
+/// - `words` - list of possible solutions
+///
+/// ## Returns ##
+///
+/// A map from feedback patterns to the number of words producing that pattern.
+pub fn get_feedback_partition(matrix: &FeedbackMatrix, guess: &String, words: &Vec<String>) -> HashMap<Pattern, usize> {
+    return matrix.partition(guess, words);
+}
+
+/// computes the Shannon entropy (in bits) of the feedback partition induced by each guess,
+/// where the solutions are weighted by the priors of the matrix
+pub fn get_information(matrix: &FeedbackMatrix, guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, f64> {
+    let mut information: HashMap<String, f64> = HashMap::new();
+    for (_, guess) in guesses.iter().enumerate() {
+        let partition = matrix.weighted_partition(guess, words);
+        let total: f64 = partition.values().map(|&(_, weight)| weight).sum();
+        let mut h: f64 = 0.;
+        for (_, &(_, weight)) in partition.iter() {
+            if weight > 0. {
+                let p = weight/total;
+                h -= p * p.log2();
+            }
+        }
+        information.insert(guess.clone(), h);
+    }
+    return information;
+}
+
+pub fn reduce_sort_by_information_then_uniqueness(matrix: &FeedbackMatrix, guesses: &mut Vec<String>, words: &Vec<String>) {
+    let information = get_information(matrix, &*guesses, words);
+    let candidates = words.iter().collect::<HashSet<&String>>();
+    let cmp = |u1: &String, u2: &String| {
+        let h1 = *information.get(u1).unwrap();
+        let h2 = *information.get(u2).unwrap();
+        let c1 = candidates.contains(u1);
+        let c2 = candidates.contains(u2);
+        let n1 = utils::nr_unique_letters(u1);
+        let n2 = utils::nr_unique_letters(u2);
+        return comparison::lexical_comparison(&vec![
+            comparison::cmp_type::<f64>(h2, h1),   // sort highest first
+            comparison::cmp_type::<bool>(c2, c1),  // sort possible solutions first
+            comparison::cmp_type::<usize>(n2, n1), // sort highest first
+        ]);
+    };
+    guesses.sort_by(cmp);
+}
+
+// ----------------------------------------------------------------
+// Tactic sort by worst case (size of largest feedback partition)
+// ----------------------------------------------------------------
+
+/// computes for each guess the size of the largest feedback partition
+pub fn get_worst_case_sizes(matrix: &FeedbackMatrix, guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, usize> {
+    let mut worst_case: HashMap<String, usize> = HashMap::new();
+    for (_, guess) in guesses.iter().enumerate() {
+        let partition = get_feedback_partition(matrix, guess, words);
+        let size = partition.values().cloned().max().unwrap_or(0);
+        worst_case.insert(guess.clone(), size);
+    }
+    return worst_case;
+}
+
+pub fn reduce_sort_by_worst_case_then_entropy_then_uniqueness(matrix: &FeedbackMatrix, guesses: &mut Vec<String>, words: &Vec<String>) {
+    let worst_case = get_worst_case_sizes(matrix, &*guesses, words);
+    let information = get_information(matrix, &*guesses, words);
+    let candidates = words.iter().collect::<HashSet<&String>>();
+    let cmp = |u1: &String, u2: &String| {
+        let s1 = *worst_case.get(u1).unwrap();
+        let s2 = *worst_case.get(u2).unwrap();
+        let h1 = *information.get(u1).unwrap();
+        let h2 = *information.get(u2).unwrap();
+        let c1 = candidates.contains(u1);
+        let c2 = candidates.contains(u2);
+        let n1 = utils::nr_unique_letters(u1);
+        let n2 = utils::nr_unique_letters(u2);
+        return comparison::lexical_comparison(&vec![
+            comparison::cmp_type::<usize>(s1, s2), // sort lowest first
+            comparison::cmp_type::<f64>(h2, h1),   // sort highest first
+            comparison::cmp_type::<bool>(c2, c1),  // sort possible solutions first
+            comparison::cmp_type::<usize>(n2, n1), // sort highest first
+        ]);
+    };
+    guesses.sort_by(cmp);
+}
+
+// ----------------------------------------------------------------
+// Tactic sort by combined information across several boards
+// ----------------------------------------------------------------
+
+/// computes for each guess the information summed over the boards (each given by its list of remaining words)
+pub fn get_combined_information(matrix: &FeedbackMatrix, guesses: &Vec<String>, boards: &Vec<Vec<String>>) -> HashMap<String, f64> {
+    let mut information: HashMap<String, f64> = guesses.iter()
+        .map(|guess| (guess.clone(), 0.))
+        .collect::<HashMap<String, f64>>();
+    for words in boards.iter() {
+        for (guess, h) in get_information(matrix, guesses, words).into_iter() {
+            *information.entry(guess).or_insert(0.) += h;
+        }
+    }
+    return information;
+}
+
+pub fn reduce_sort_by_combined_information_then_uniqueness(matrix: &FeedbackMatrix, guesses: &mut Vec<String>, boards: &Vec<Vec<String>>) {
+    let information = get_combined_information(matrix, &*guesses, boards);
+    // number of boards, which a guess certainly resp. possibly solves:
+    let mut certain: HashMap<&String, usize> = HashMap::new();
+    let mut possible: HashMap<&String, usize> = HashMap::new();
+    for words in boards.iter() {
+        for word in words.iter() {
+            *possible.entry(word).or_insert(0) += 1;
+            if words.len() == 1 {
+                *certain.entry(word).or_insert(0) += 1;
+            }
+        }
+    }
+    let cmp = |u1: &String, u2: &String| {
+        let k1 = *certain.get(u1).unwrap_or(&0);
+        let k2 = *certain.get(u2).unwrap_or(&0);
+        let h1 = *information.get(u1).unwrap();
+        let h2 = *information.get(u2).unwrap();
+        let c1 = *possible.get(u1).unwrap_or(&0);
+        let c2 = *possible.get(u2).unwrap_or(&0);
+        let n1 = utils::nr_unique_letters(u1);
+        let n2 = utils::nr_unique_letters(u2);
+        return comparison::lexical_comparison(&vec![
+            comparison::cmp_type::<usize>(k2, k1), // sort certain solutions first
             comparison::cmp_type::<f64>(h2, h1),   // sort highest first
+            comparison::cmp_type::<usize>(c2, c1), // sort possible solutions first
             comparison::cmp_type::<usize>(n2, n1), // sort highest first
         ]);
     };
-    words.sort_by(cmp);
+    guesses.sort_by(cmp);
 }
@@ -5,6 +5,7 @@
 extern crate mint;
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use crate::core::utils;
 use crate::core::comparison;
@@ -31,6 +32,11 @@ pub fn reduce_to_words_with_unique_letters(words: &Vec<String>) -> Vec<String> {
 // ----------------------------------------------------------------
 
 pub fn get_entropy(words: &Vec<String>) -> HashMap<String, f64> {
+    return get_entropy_of_guesses(words, words);
+}
+
+/// computes the entropy of guesses w.r.t. the letter frequencies in a list of words
+pub fn get_entropy_of_guesses(guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, f64> {
     let counts = utils::get_letter_frequencies_in_strings(words);
     let mut entropy_letter: HashMap<String, f64> = HashMap::new();
     let mut entropy: HashMap<String, f64> = HashMap::new();
@@ -43,10 +49,10 @@ pub fn get_entropy(words: &Vec<String>) -> HashMap<String, f64> {
         let h = -p * p.log2();
         entropy_letter.insert(letter.clone(), h);
     }
-    for (_, word) in words.iter().enumerate() {
+    for (_, word) in guesses.iter().enumerate() {
         let mut h: f64 = 0.;
         for (_, a) in word.chars().enumerate() {
-            h += entropy_letter.get(&a.to_string()).unwrap();
+            h += entropy_letter.get(&a.to_string()).unwrap_or(&0.);
         }
         entropy.insert(word.clone(), h);
     }
@@ -78,3 +84,22 @@ pub fn reduce_sort_by_entropy_then_uniqueness(words: &mut Vec<String>) {
     };
     words.sort_by(cmp);
 }
+
+pub fn reduce_sort_guesses_by_entropy_then_uniqueness(guesses: &mut Vec<String>, words: &Vec<String>) {
+    let entropy = get_entropy_of_guesses(&*guesses, words);
+    let candidates = words.iter().collect::<HashSet<&String>>();
+    let cmp = |u1: &String, u2: &String| {
+        let h1 = *entropy.get(u1).unwrap();
+        let h2 = *entropy.get(u2).unwrap();
+        let c1 = candidates.contains(u1);
+        let c2 = candidates.contains(u2);
+        let n1 = utils::nr_unique_letters(u1);
+        let n2 = utils::nr_unique_letters(u2);
+        return comparison::lexical_comparison(&vec![
+            comparison::cmp_type::<f64>(h2, h1),   // sort highest first
+            comparison::cmp_type::<bool>(c2, c1),  // sort possible solutions first
+            comparison::cmp_type::<usize>(n2, n1), // sort highest first
+        ]);
+    };
+    guesses.sort_by(cmp);
+}
@@ -1,2 +1,3 @@
 pub mod advanced;
 pub mod basic;
+pub mod selection;
@@ -0,0 +1,128 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::app::patterns::FeedbackMatrix;
+
+use super::basic;
+use super::advanced;
+
+// ----------------------------------------------------------------
+// Structure Tactic
+// ----------------------------------------------------------------
+
+/// The tactics available for ranking guesses.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::app::tactics::selection::Tactic;
+/// let tactic = Tactic::from_name("information").unwrap();
+/// assert_eq!(tactic.name(), "information");
+/// assert!(Tactic::from_name("unknown").is_none());
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Tactic {
+    Entropy,
+    Distance,
+    RemainingSize,
+    Information,
+    Minimax,
+}
+
+// ----------------------------------------------------------------
+// Implementation Tactic
+// ----------------------------------------------------------------
+
+impl Tactic {
+    pub fn all() -> Vec<Tactic> {
+        return vec![
+            Tactic::Entropy,
+            Tactic::Distance,
+            Tactic::RemainingSize,
+            Tactic::Information,
+            Tactic::Minimax,
+        ];
+    }
+
+    pub fn from_name(name: &str) -> Option<Tactic> {
+        return Tactic::all()
+            .into_iter()
+            .find(|tactic| tactic.name() == name.trim().to_lowercase());
+    }
+
+    pub fn name(self: &Self) -> &'static str {
+        match self {
+            Tactic::Entropy => "entropy",
+            Tactic::Distance => "distance",
+            Tactic::RemainingSize => "remaining-size",
+            Tactic::Information => "information",
+            Tactic::Minimax => "minimax",
+        }
+    }
+
+    /// describes the score by which the tactic primarily ranks guesses
+    pub fn score_name(self: &Self) -> &'static str {
+        match self {
+            Tactic::Entropy => "letter-entropy",
+            Tactic::Distance => "average-incorrect-letters",
+            Tactic::RemainingSize => "expected-remaining",
+            Tactic::Information => "information-bits",
+            Tactic::Minimax => "worst-case-remaining",
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+// Method apply tactic
+// ----------------------------------------------------------------
+
+/// sorts list of guesses by how well they narrow down the remaining words, according to the chosen tactic
+pub fn reduce_sort_by_tactic(tactic: &Tactic, matrix: &FeedbackMatrix, guesses: &mut Vec<String>, words: &Vec<String>) {
+    match tactic {
+        Tactic::Entropy => {
+            basic::reduce_sort_guesses_by_entropy_then_uniqueness(guesses, words);
+        },
+        Tactic::Distance => {
+            advanced::reduce_sort_by_distance_then_entropy_then_uniqueness(matrix, guesses, words);
+        },
+        Tactic::RemainingSize => {
+            advanced::reduce_sort_by_remaining_size_then_entropy_then_uniqueness(matrix, guesses, words);
+        },
+        Tactic::Information => {
+            advanced::reduce_sort_by_information_then_uniqueness(matrix, guesses, words);
+        },
+        Tactic::Minimax => {
+            advanced::reduce_sort_by_worst_case_then_entropy_then_uniqueness(matrix, guesses, words);
+        },
+    }
+}
+
+/// computes the score by which the chosen tactic primarily ranks guesses (cf. `Tactic::score_name`)
+pub fn get_scores_by_tactic(tactic: &Tactic, matrix: &FeedbackMatrix, guesses: &Vec<String>, words: &Vec<String>) -> HashMap<String, f64> {
+    match tactic {
+        Tactic::Entropy => {
+            return basic::get_entropy_of_guesses(guesses, words);
+        },
+        Tactic::Distance => {
+            return advanced::get_distances(matrix, guesses, words)
+                .into_iter()
+                .map(|(word, (n_incorrect, _))| (word, n_incorrect))
+                .collect::<HashMap<String, f64>>();
+        },
+        Tactic::RemainingSize => {
+            return advanced::get_average_size_of_remaining_words(matrix, guesses, words);
+        },
+        Tactic::Information => {
+            return advanced::get_information(matrix, guesses, words);
+        },
+        Tactic::Minimax => {
+            return advanced::get_worst_case_sizes(matrix, guesses, words)
+                .into_iter()
+                .map(|(word, size)| (word, size as f64))
+                .collect::<HashMap<String, f64>>();
+        },
+    }
+}
@@ -0,0 +1,229 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate serde_json;
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use self::serde_json::Value;
+
+use crate::app::patterns;
+use crate::app::patterns::FeedbackMatrix;
+use crate::app::patterns::Pattern;
+use crate::app::solver;
+use crate::app::states::WordlState;
+use crate::core::utils;
+use crate::setup::config::ConfigParams;
+
+// ----------------------------------------------------------------
+// Structure DecisionTree
+// ----------------------------------------------------------------
+
+/// A precomputed strategy: the guess to make at this point and,
+/// for every feedback pattern other than the correct one, the strategy to continue with.
+pub struct DecisionTree {
+    pub guess: String,
+    /// number of possible solutions before the guess is made
+    pub remaining: usize,
+    pub branches: BTreeMap<Pattern, DecisionTree>,
+}
+
+// ----------------------------------------------------------------
+// Implementation DecisionTree
+// ----------------------------------------------------------------
+
+impl DecisionTree {
+    /// number of guesses needed in the worst case
+    pub fn depth(self: &Self) -> usize {
+        return 1 + self.branches.values().map(|tree| tree.depth()).max().unwrap_or(0);
+    }
+
+    /// number of guesses needed summed over all possible solutions
+    pub fn total_guesses(self: &Self) -> usize {
+        return self.remaining + self.branches.values().map(|tree| tree.total_guesses()).sum::<usize>();
+    }
+
+    /// follows the tree along previous guesses and their feedback.
+    ///
+    /// ## Returns ##
+    ///
+    /// The subtree for the current state, or `None` if the guesses deviated from the tree
+    /// or the feedback does not occur in it.
+    pub fn follow<'life>(self: &'life Self, history: &Vec<WordlState>) -> Option<&'life DecisionTree> {
+        let mut node = self;
+        for state in history.iter() {
+            if !(state.to_word() == node.guess) {
+                return None;
+            }
+            node = node.branches.get(&state.to_pattern())?;
+        }
+        return Some(node);
+    }
+
+    /// checks that every guess in the tree has the right length and is an allowed guess
+    /// (a tree built from other word lists cannot be followed).
+    ///
+    /// ## Returns ##
+    ///
+    /// `Err(reason)` for the first guess, which does not fit.
+    ///
+    /// ## Examples ##
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use wordle::app::tree::DecisionTree;
+    /// let mut branches = BTreeMap::new();
+    /// branches.insert(0, DecisionTree { guess: String::from("pilot"), remaining: 1, branches: BTreeMap::new() });
+    /// let tree = DecisionTree { guess: String::from("crane"), remaining: 2, branches };
+    /// let words = vec![String::from("crane"), String::from("pilot")];
+    /// assert!(tree.check_words(5, &words).is_ok());
+    /// assert!(tree.check_words(6, &words).is_err());
+    /// assert!(tree.check_words(5, &vec![String::from("crane")]).is_err());
+    /// ```
+    pub fn check_words(self: &Self, size_of_word: usize, guesses: &Vec<String>) -> Result<(), String> {
+        let guesses = guesses.iter().collect::<HashSet<&String>>();
+        return self.check_words_in(size_of_word, &guesses);
+    }
+
+    fn check_words_in(self: &Self, size_of_word: usize, guesses: &HashSet<&String>) -> Result<(), String> {
+        if !(utils::length_of_word(&self.guess) == size_of_word) {
+            return Err(format!("the guess `{}` does not have {} letters", self.guess, size_of_word));
+        }
+        if !guesses.contains(&self.guess) {
+            return Err(format!("the guess `{}` is not in the list of words", self.guess));
+        }
+        for (&pattern, tree) in self.branches.iter() {
+            if !((pattern as usize) < patterns::number_of_patterns(size_of_word)) {
+                return Err(format!("the feedback {} after `{}` does not exist for {} letters", pattern, self.guess, size_of_word));
+            }
+            tree.check_words_in(size_of_word, guesses)?;
+        }
+        return Ok(());
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods - build
+// ----------------------------------------------------------------
+
+/// builds a decision tree from an opening word.
+///
+/// At every node the top `breadth` suggestions of the configured tactic are tried
+/// and the one minimising the total number of guesses (within the depth limit) is kept.
+/// With `breadth = 1` the tree simply follows the tactic.
+///
+/// ## Arguments ##
+///
+/// - `config` - settings (tactic, hard mode, limits)
+/// - `matrix` - precomputed feedback patterns (contains the list of allowed guesses)
+/// - `words_remaining` - list of possible solutions
+/// - `start` - the opening word
+/// - `breadth` - number of candidate guesses tried at each node
+/// - `max_depth` - maximal number of guesses permitted
+///
+/// ## Returns ##
+///
+/// The tree, or an error if no tree within the depth limit was found.
+pub fn build_tree(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    words_remaining: &Vec<String>,
+    start: &String,
+    breadth: usize,
+    max_depth: usize,
+) -> Result<DecisionTree, String> {
+    return build_node(config, matrix, &vec![], words_remaining, start, breadth.max(1), max_depth)
+        .ok_or_else(|| format!("No decision tree starting with `{}` solves every word within {} guesses.", start, max_depth));
+}
+
+fn build_node(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    history: &Vec<WordlState>,
+    words: &Vec<String>,
+    guess: &String,
+    breadth: usize,
+    max_depth: usize,
+) -> Option<DecisionTree> {
+    if max_depth == 0 {
+        return None;
+    }
+    let size_of_word = utils::length_of_word(guess);
+    let correct = patterns::pattern_correct(size_of_word);
+    let mut partition: BTreeMap<Pattern, Vec<String>> = BTreeMap::new();
+    for word in words.iter() {
+        partition.entry(matrix.get(guess, word)).or_insert(vec![]).push(word.clone());
+    }
+    let mut branches: BTreeMap<Pattern, DecisionTree> = BTreeMap::new();
+    for (pattern, words_) in partition.into_iter() {
+        if pattern == correct {
+            continue;
+        }
+        let mut history_ = history.clone();
+        history_.push(WordlState::new(guess, &patterns::pattern_to_feedback(pattern, size_of_word)));
+        let tree = build_best_node(config, matrix, &history_, &words_, breadth, max_depth - 1)?;
+        branches.insert(pattern, tree);
+    }
+    return Some(DecisionTree { guess: guess.clone(), remaining: words.len(), branches });
+}
+
+fn build_best_node(
+    config: &ConfigParams,
+    matrix: &FeedbackMatrix,
+    history: &Vec<WordlState>,
+    words: &Vec<String>,
+    breadth: usize,
+    max_depth: usize,
+) -> Option<DecisionTree> {
+    let candidates = if words.len() <= 2 {
+        words.clone()
+    } else {
+        solver::get_suggestions(config, matrix, history, words)
+    };
+    // no tree can do better than guessing a solution, which then separates all other solutions:
+    let lower_bound = 2*words.len() - 1;
+    let mut best: Option<DecisionTree> = None;
+    for guess in candidates.iter().take(breadth) {
+        let tree = match build_node(config, matrix, history, words, guess, breadth, max_depth) {
+            Some(tree) => tree,
+            None => { continue; },
+        };
+        let better = best.as_ref().map(|best| tree.total_guesses() < best.total_guesses()).unwrap_or(true);
+        if better {
+            best = Some(tree);
+        }
+        if best.as_ref().map(|best| best.total_guesses() <= lower_bound).unwrap_or(false) {
+            break;
+        }
+    }
+    return best;
+}
+
+// ----------------------------------------------------------------
+// Methods - load
+// ----------------------------------------------------------------
+
+/// loads a decision tree, which was exported as json (see `display::json::display_tree_json`).
+pub fn load_tree(path: &str) -> Result<DecisionTree, String> {
+    let contents = utils::read_file(&utils::expand_home_directory(path))
+        .map_err(|err| format!("Could not read decision tree `{}`: {}", path, err))?;
+    let value: Value = serde_json::from_str(&contents)
+        .map_err(|err| format!("Could not parse decision tree `{}`: {}", path, err))?;
+    return tree_from_json(&value["tree"])
+        .ok_or_else(|| format!("Decision tree `{}` is malformed.", path));
+}
+
+fn tree_from_json(value: &Value) -> Option<DecisionTree> {
+    let guess = value["guess"].as_str()?.to_lowercase();
+    let remaining = value["remaining"].as_u64()? as usize;
+    let mut branches: BTreeMap<Pattern, DecisionTree> = BTreeMap::new();
+    for (feedback, branch) in value["branches"].as_object()?.iter() {
+        if !(utils::length_of_word(feedback) == utils::length_of_word(&guess)) {
+            return None;
+        }
+        branches.insert(patterns::pattern_from_feedback(feedback), tree_from_json(branch)?);
+    }
+    return Some(DecisionTree { guess, remaining, branches });
+}
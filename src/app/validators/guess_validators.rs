@@ -6,30 +6,59 @@ extern crate dyn_fmt;
 
 use self::dyn_fmt::AsStrFormatExt;
 
+use crate::core::console;
 use crate::core::utils;
 use crate::setup::config::ConfigParams;
+use crate::app::patterns;
+use crate::app::states::WordlState;
+use crate::display::basic::display_state;
 
 // ----------------------------------------------------------------
 // Validators
 // ----------------------------------------------------------------
 
-pub fn validate_guess(guess: &String, config: &ConfigParams) -> bool {
+pub fn validate_guess(guess: &String, config: &ConfigParams, history: &Vec<WordlState>) -> bool {
     let n = config.size_of_wordle;
     let re1 = utils::construct_regex(r"^\w*$");
     let re2 = utils::construct_regex(r"^\D*$");
-    if utils::length_of_word(guess) == n && re1.is_match(guess) && re2.is_match(guess) {
-        return true;
-    } else {
-        println!("{}\n", utils::dedent_ignore_first_last(
+    if !(utils::length_of_word(guess) == n && re1.is_match(guess) && re2.is_match(guess)) {
+        println!("{}\n", console::paint(&utils::dedent_ignore_first_last(
             "
             [\x1b[91mERROR\x1b[0m] Invalid Guess!
 
             - Must consist of letters.
             - Length of guess must be {}.
             "
-        ).format(&[n]));
+        ).format(&[n])));
         return false;
     }
+    if config.hard_mode {
+        for state in history.iter() {
+            match state.hard_mode_violation(guess) {
+                Some(rule) => {
+                    println!("{}\n", console::paint(&utils::dedent_ignore_first_last(
+                        "
+                        [\x1b[91mERROR\x1b[0m] Invalid Guess (hard mode)!
+
+                        - Revealed hints must be used in subsequent guesses.
+                        - After {} the {}.
+                        "
+                    ).format(&[display_state(state), rule])));
+                    return false;
+                },
+                None => { },
+            }
+        }
+    }
+    return true;
+}
+
+pub fn validate_guess_in_list(guess: &String, words: &Vec<String>) -> bool {
+    if words.contains(&guess.to_lowercase()) {
+        return true;
+    }
+    println!("{}\n", console::paint(&format!("[\x1b[91mERROR\x1b[0m] Invalid Guess! \x1b[1m{}\x1b[0m is not in the list of words.", guess)));
+    return false;
 }
 
 pub fn validate_feedback(_guess: &String, feedback: &String, config: &ConfigParams) -> bool {
@@ -38,15 +67,15 @@ pub fn validate_feedback(_guess: &String, feedback: &String, config: &ConfigPara
     if utils::length_of_word(feedback) == n && re.is_match(feedback) {
         return true;
     } else {
-        print!("{}\n", utils::dedent_ignore_first_last(
+        print!("{}\n", console::paint(&utils::dedent_ignore_first_last(
             "
             [\x1b[91mERROR\x1b[0m] Invalid Feedback option!
 
-            - Format must only contain the symbols: 0, 1, -
-            - Length of feedback must match length of guess.
+            - Format must only contain the symbols: 0 (or x), 1, -
+            - Length of feedback must be {} (the length of the guess), e.g. {}.
             - Letters marked (correct|partially correct|incorrect) must be disjoint!
             "
-        ).format(&[n]));
+        ).format(&[n.to_string(), patterns::example_feedback(n)])));
         return false;
     }
 }
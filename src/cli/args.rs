@@ -4,9 +4,13 @@
 
 extern crate argparse;
 
+use std::io;
+
 use self::argparse::ArgumentParser;
 use self::argparse::StoreTrue;
 use self::argparse::Store;
+use self::argparse::List;
+use self::argparse::Collect;
 
 // ----------------------------------------------------------------
 // Argument Parser - data structure
@@ -15,7 +19,50 @@ use self::argparse::Store;
 pub struct CmdArguments {
     pub quiet: bool,
     pub path: String,
+    pub path_solutions: String,
+    pub dict: String,
+    pub path_frequencies: String,
+    pub path_config: String,
+    pub length: usize,
     pub interactive: bool,
+    pub tactic: String,
+    pub format: String,
+    pub path_tree: String,
+    pub boards: usize,
+    pub resume: bool,
+    pub command: String,
+    pub arguments: Vec<String>,
+}
+
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+pub struct SuggestArguments {
+    pub history: Vec<String>,
+}
+
+pub struct TreeArguments {
+    pub start: String,
+    pub breadth: usize,
+    pub depth: usize,
+    pub output: String,
+}
+
+pub struct PlayArguments {
+    pub seed: String,
+    pub hard: bool,
+}
+
+pub struct ConfigArguments {
+    pub action: String,
+}
+
+pub struct BenchArguments {
+    pub tactics: Vec<String>,
+    pub starts: Vec<String>,
+    pub limit: usize,
 }
 
 // ----------------------------------------------------------------
@@ -25,7 +72,19 @@ pub struct CmdArguments {
 pub fn construct_arg_parser() -> CmdArguments {
     let mut quiet = false;
     let mut interactive = false;
-    let mut path = "World".to_string();
+    let mut path = "".to_string();
+    let mut path_solutions = "".to_string();
+    let mut dict = "".to_string();
+    let mut path_frequencies = "".to_string();
+    let mut path_config = "".to_string();
+    let mut length: usize = 0;
+    let mut tactic = "".to_string();
+    let mut format = "text".to_string();
+    let mut path_tree = "".to_string();
+    let mut boards: usize = 1;
+    let mut resume = false;
+    let mut command = "".to_string();
+    let mut arguments = Vec::<String>::new();
     {  // this block limits scope of borrows by parser.refer() method
         let mut parser = ArgumentParser::new();
         parser.set_description("The wordle guessing aid.");
@@ -39,14 +98,87 @@ pub fn construct_arg_parser() -> CmdArguments {
             .add_option(
                 &["-p", "--path"],
                 Store,
-                "Path to list of possible words."
+                "Path to list of possible words (defaults to the bundled list)."
+            );
+        parser.refer(&mut path_solutions)
+            .add_option(
+                &["--solutions"],
+                Store,
+                "Path to list of possible solutions (defaults to the bundled list)."
+            );
+        parser.refer(&mut dict)
+            .add_option(
+                &["--dict"],
+                Store,
+                "List of possible solutions: name of a bundled list (nyt | full, see command dicts) or path to a file. Defaults to nyt."
+            );
+        parser.refer(&mut path_frequencies)
+            .add_option(
+                &["--frequencies"],
+                Store,
+                "Path to a file of word frequencies (word and count per line, or words ranked from most to least common), which weights the solutions."
+            );
+        parser.refer(&mut path_config)
+            .add_option(
+                &["--config"],
+                Store,
+                "Path to a config file overriding the defaults (defaults to $XDG_CONFIG_HOME/wordle/config.yml, if it exists)."
+            );
+        parser.refer(&mut length)
+            .add_option(
+                &["-l", "--length"],
+                Store,
+                "Length of words (3 to 12), the word lists are restricted to words of this length (setting size-of-wordle)."
             );
         parser.refer(&mut interactive)
             .add_option(
                 &["--it"],
                 StoreTrue,
-                "Run in interactive mode."
+                "Run in interactive mode (otherwise suggestions are printed once)."
+            );
+        parser.refer(&mut resume)
+            .add_option(
+                &["--resume"],
+                StoreTrue,
+                "Resume the last unfinished session of the interactive solver (implies --it)."
+            );
+        parser.refer(&mut tactic)
+            .add_option(
+                &["-t", "--tactic"],
+                Store,
+                "Tactic used to rank guesses (entropy | distance | remaining-size | information | minimax)."
             );
+        parser.refer(&mut format)
+            .add_option(
+                &["-f", "--format"],
+                Store,
+                "Format of (non-interactive) output (text | json)."
+            );
+        parser.refer(&mut path_tree)
+            .add_option(
+                &["--tree"],
+                Store,
+                "Path to a decision tree (json), which the interactive solver follows instead of ranking guesses."
+            );
+        parser.refer(&mut boards)
+            .add_option(
+                &["--boards"],
+                Store,
+                "Number of boards sharing each guess (e.g. 2 = Dordle, 4 = Quordle, 8 = Octordle). Requires --it."
+            );
+        parser.refer(&mut command)
+            .add_argument(
+                "command",
+                Store,
+                "Command to run (suggest | bench | tree | play | daily | absurdle | stats | config | dicts). If omitted, runs the solver."
+            );
+        parser.refer(&mut arguments)
+            .add_argument(
+                "arguments",
+                List,
+                "Arguments for the command."
+            );
+        parser.stop_on_first_argument(true);
         match parser.parse_args() {
             Ok(()) => {
 
@@ -67,5 +199,156 @@ pub fn construct_arg_parser() -> CmdArguments {
             },
         }
     }
-    return CmdArguments { quiet, path, interactive };
+    return CmdArguments { quiet, path, path_solutions, dict, path_frequencies, path_config, length, interactive, tactic, format, path_tree, boards, resume, command, arguments };
+}
+
+// ----------------------------------------------------------------
+// Output format
+// ----------------------------------------------------------------
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        match name.trim().to_lowercase().as_str() {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+// Argument Parser - commands
+// ----------------------------------------------------------------
+
+pub fn construct_suggest_parser(arguments: &Vec<String>) -> SuggestArguments {
+    let mut history = Vec::<String>::new();
+    {
+        let mut parser = ArgumentParser::new();
+        parser.set_description("Prints suggestions for the next guess, given previous guesses and their feedback.");
+        parser.refer(&mut history)
+            .add_option(
+                &["--history"],
+                Collect,
+                "A previous guess and its feedback, e.g. crane:xx-x1 (can be repeated)."
+            );
+        parse_command(&parser, "suggest", arguments);
+    }
+    return SuggestArguments { history };
+}
+
+pub fn construct_tree_parser(arguments: &Vec<String>) -> TreeArguments {
+    let mut start = "".to_string();
+    let mut breadth: usize = 1;
+    let mut depth: usize = 0;
+    let mut output = "".to_string();
+    {
+        let mut parser = ArgumentParser::new();
+        parser.set_description("Builds a decision tree from an opening word.");
+        parser.refer(&mut start)
+            .add_option(
+                &["-s", "--start"],
+                Store,
+                "Opening word. Defaults to the choice of the tactic."
+            );
+        parser.refer(&mut breadth)
+            .add_option(
+                &["-b", "--breadth"],
+                Store,
+                "Number of best guesses tried at each node (1 = follow the tactic)."
+            );
+        parser.refer(&mut depth)
+            .add_option(
+                &["-d", "--depth"],
+                Store,
+                "Maximal number of guesses (0 = max-guesses from the config)."
+            );
+        parser.refer(&mut output)
+            .add_option(
+                &["-o", "--output"],
+                Store,
+                "Path to write the tree to (defaults to the console)."
+            );
+        parse_command(&parser, "tree", arguments);
+    }
+    return TreeArguments { start, breadth, depth, output };
+}
+
+pub fn construct_play_parser(arguments: &Vec<String>) -> PlayArguments {
+    let mut seed = "".to_string();
+    let mut hard = false;
+    {
+        let mut parser = ArgumentParser::new();
+        parser.set_description("Hides a word from the list of solutions for you to guess.");
+        parser.refer(&mut seed)
+            .add_option(
+                &["--seed"],
+                Store,
+                "Seed (non-negative integer) for choosing the word. Defaults to a random choice."
+            );
+        parser.refer(&mut hard)
+            .add_option(
+                &["--hard"],
+                StoreTrue,
+                "Play in hard mode (regardless of the setting hard-mode)."
+            );
+        parse_command(&parser, "play", arguments);
+    }
+    return PlayArguments { seed, hard };
+}
+
+pub fn construct_bench_parser(arguments: &Vec<String>) -> BenchArguments {
+    let mut tactics = Vec::<String>::new();
+    let mut starts = Vec::<String>::new();
+    let mut limit: usize = 0;
+    {
+        let mut parser = ArgumentParser::new();
+        parser.set_description("Lets the solver play against every possible solution and reports statistics.");
+        parser.refer(&mut tactics)
+            .add_option(
+                &["-t", "--tactic"],
+                Collect,
+                "Tactic to benchmark (can be repeated). Defaults to all tactics."
+            );
+        parser.refer(&mut starts)
+            .add_option(
+                &["-s", "--start"],
+                Collect,
+                "Fixed first guess (can be repeated). Defaults to the choice of the tactic."
+            );
+        parser.refer(&mut limit)
+            .add_option(
+                &["-n", "--limit"],
+                Store,
+                "Only play against the first n solutions (0 = all)."
+            );
+        parse_command(&parser, "bench", arguments);
+    }
+    return BenchArguments { tactics, starts, limit };
+}
+
+pub fn construct_config_parser(arguments: &Vec<String>) -> ConfigArguments {
+    let mut action = "show".to_string();
+    {
+        let mut parser = ArgumentParser::new();
+        parser.set_description("Shows or checks the settings (defaults, config file, environment, command line).");
+        parser.refer(&mut action)
+            .add_argument(
+                "action",
+                Store,
+                "Action to perform (show | check)."
+            );
+        parse_command(&parser, "config", arguments);
+    }
+    return ConfigArguments { action };
+}
+
+fn parse_command(parser: &ArgumentParser, command: &str, arguments: &Vec<String>) {
+    let mut args = vec![format!("wordle {}", command)];
+    args.extend(arguments.iter().cloned());
+    match parser.parse(args, &mut io::stdout(), &mut io::stderr()) {
+        Ok(()) => { },
+        Err(code) => {
+            std::process::exit(code);
+        },
+    }
 }
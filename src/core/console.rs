@@ -6,6 +6,8 @@ extern crate crossterm;
 
 use std::io;
 use std::io::Write; // !!! need this to perform flush !!!
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use self::crossterm::execute;
 use self::crossterm::cursor as CrossTermCursor;
@@ -19,6 +21,12 @@ use self::crossterm::terminal::enable_raw_mode;
 use self::crossterm::terminal::disable_raw_mode;
 use super::utils;
 
+// ----------------------------------------------------------------
+// Settings
+// ----------------------------------------------------------------
+
+static COLOURED: AtomicBool = AtomicBool::new(true);
+
 // ----------------------------------------------------------------
 // Structure
 // ----------------------------------------------------------------
@@ -194,6 +202,38 @@ impl ConsoleTextState {
     }
 }
 
+// ----------------------------------------------------------------
+// Methods colours
+// ----------------------------------------------------------------
+
+/// turns ANSI colours / styles in console output on or off
+pub fn set_coloured(coloured: bool) {
+    COLOURED.store(coloured, Ordering::Relaxed);
+}
+
+pub fn is_coloured() -> bool {
+    return COLOURED.load(Ordering::Relaxed);
+}
+
+/// prepares text for the console, i.e. removes ANSI escape codes if colours are turned off
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::console::paint;
+/// use wordle::core::console::set_coloured;
+/// assert_eq!(paint("\x1b[1mbold\x1b[0m"), "\x1b[1mbold\x1b[0m");
+/// set_coloured(false);
+/// assert_eq!(paint("[\x1b[93;1mWARNING\x1b[0m] text"), "[WARNING] text");
+/// ```
+pub fn paint(text: &str) -> String {
+    if is_coloured() {
+        return text.to_string();
+    }
+    let re = utils::construct_regex(r"\x1b\[[0-9;]*m");
+    return re.replace_all(text, "").to_string();
+}
+
 // ----------------------------------------------------------------
 // Method
 // ----------------------------------------------------------------
@@ -226,7 +266,7 @@ pub fn interaction(message: &str) -> ConsoleResponse<String> {
     let mut stdout = io::stdout();
 
     // Initialise the console:
-    print!("{}", message);
+    print!("{}", paint(message));
     stdout.flush().expect("Could not write to console");
 
     // enter raw mode then read input until suitable break command
@@ -0,0 +1,85 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::core::utils;
+
+// ----------------------------------------------------------------
+// NOTE
+// ----------------------------------------------------------------
+
+// Dates are represented by the number of days since 1970-01-01 (in the proleptic Gregorian calendar).
+// The conversions follow H. Hinnant's algorithms `days_from_civil` and `civil_from_days`.
+
+// ----------------------------------------------------------------
+// Methods
+// ----------------------------------------------------------------
+
+/// the current date (in UTC)
+pub fn today() -> i64 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+    return seconds.div_euclid(86400);
+}
+
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2)/5 + day - 1;
+    let doe = yoe * 365 + yoe/4 - yoe/100 + doy;
+    return era * 146097 + doe - 719468;
+}
+
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe/1460 + doe/36524 - doe/146096)/365;
+    let doy = doe - (365 * yoe + yoe/4 - yoe/100);
+    let mp = (5 * doy + 2)/153;
+    let day = doy - (153 * mp + 2)/5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    return (year, month, day);
+}
+
+/// reads dates of the form `YYYY-MM-DD`
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::dates::format_date;
+/// use wordle::core::dates::parse_date;
+/// assert_eq!(parse_date("1970-01-01"), Some(0));
+/// assert_eq!(parse_date("2021-06-19"), Some(18797));
+/// assert_eq!(format_date(18797), "2021-06-19");
+/// assert_eq!(parse_date("2021-02-30"), None);
+/// assert_eq!(parse_date("19 June 2021"), None);
+/// ```
+pub fn parse_date(text: &str) -> Option<i64> {
+    let re = utils::construct_regex(r"^(\d{4})-(\d{2})-(\d{2})$");
+    let captures = re.captures(text.trim())?;
+    let year = captures[1].parse::<i64>().ok()?;
+    let month = captures[2].parse::<i64>().ok()?;
+    let day = captures[3].parse::<i64>().ok()?;
+    if !(1 <= month && month <= 12 && 1 <= day && day <= 31) {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    // reject days beyond the end of the month:
+    if !(civil_from_days(days) == (year, month, day)) {
+        return None;
+    }
+    return Some(days);
+}
+
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    return format!("{:04}-{:02}-{:02}", year, month, day);
+}
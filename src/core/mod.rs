@@ -1,3 +1,5 @@
 pub mod utils;
 pub mod comparison;
 pub mod console;
+pub mod random;
+pub mod dates;
@@ -0,0 +1,61 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+// ----------------------------------------------------------------
+// Structure Random
+// ----------------------------------------------------------------
+
+/// A small (non-cryptographic) xorshift generator, so that games can be replayed from a seed.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::random::Random;
+/// let mut rng1 = Random::new(42);
+/// let mut rng2 = Random::new(42);
+/// assert_eq!(rng1.next_u64(), rng2.next_u64());
+/// assert!(rng1.below(10) < 10);
+/// ```
+pub struct Random {
+    state: u64,
+}
+
+// ----------------------------------------------------------------
+// Implementation Random
+// ----------------------------------------------------------------
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        // NOTE: the state must never be 0 and neighbouring seeds should not give similar sequences.
+        let mut state = seed ^ 0x9e3779b97f4a7c15;
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d049bb133111eb);
+        state ^= state >> 31;
+        return Random { state: if state == 0 { 1 } else { state } };
+    }
+
+    /// seeds the generator from the system clock
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+        return Random::new(nanos);
+    }
+
+    pub fn next_u64(self: &mut Self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        return self.state;
+    }
+
+    /// a number in `0..n` (`n` must be positive)
+    pub fn below(self: &mut Self, n: usize) -> usize {
+        return (self.next_u64() % (n as u64)) as usize;
+    }
+}
@@ -12,6 +12,7 @@ use std::collections::HashMap;
 
 use self::regex::Regex;
 use self::rust_embed::EmbeddedFile;
+use self::yaml_rust::YamlEmitter;
 use self::yaml_rust::YamlLoader;
 use self::yaml_rust::Yaml;
 
@@ -92,6 +93,16 @@ pub fn read_contents_to_yaml(source: &String) -> Result<Yaml, io::Error> {
         });
 }
 
+pub fn write_yaml(path: &str, spec: &Yaml) -> Result<(), io::Error> {
+    let mut contents = String::new();
+    YamlEmitter::new(&mut contents)
+        .dump(spec)
+        .map_err(|_| {
+            return io::Error::new(io::ErrorKind::InvalidData, "Could not write yaml!")
+        })?;
+    return std::fs::write(path, contents + "\n");
+}
+
 // ----------------------------------------------------------------
 // Methods - read from embedded
 // ----------------------------------------------------------------
@@ -151,6 +162,28 @@ pub fn length_of_word(text: &String) -> usize {
     return text.chars().count();
 }
 
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::utils::ordinal;
+/// assert_eq!(ordinal(1), "1st");
+/// assert_eq!(ordinal(2), "2nd");
+/// assert_eq!(ordinal(3), "3rd");
+/// assert_eq!(ordinal(4), "4th");
+/// assert_eq!(ordinal(12), "12th");
+/// assert_eq!(ordinal(22), "22nd");
+/// ```
+pub fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    return format!("{}{}", n, suffix);
+}
+
 pub fn dedent_ignore_first_last(text: &str) -> String {
     let re = construct_regex(r"(^\s*\n)|(\n\s*$)");
     let text_remove_first_last = re.replace_all(text, "").to_string();
@@ -204,6 +237,43 @@ pub fn nr_unique_letters(text: &String) -> usize {
     return counts.len();
 }
 
+/// computes a (stable) FNV-1a hash of a list of strings, e.g. to identify word lists
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::utils::hash_strings;
+/// let words = vec![String::from("crane"), String::from("react")];
+/// assert_eq!(hash_strings(&words), hash_strings(&words.clone()));
+/// assert_ne!(hash_strings(&words), hash_strings(&vec![String::from("cranereact")]));
+/// ```
+pub fn hash_strings(words: &Vec<String>) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for word in words.iter() {
+        // NOTE: terminate each word, so that the splitting of the list matters:
+        for byte in word.bytes().chain(std::iter::once(b'\n')) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    return format!("{:016x}", hash);
+}
+
+// ----------------------------------------------------------------
+// METHODS paths
+// ----------------------------------------------------------------
+
+/// replaces a leading `~` by the home directory of the user
+pub fn expand_home_directory(path: &str) -> String {
+    if path == "~" || path.starts_with("~/") {
+        match std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
+            Ok(home) => { return format!("{}{}", home, &path[1..]); },
+            Err(_) => { },
+        }
+    }
+    return path.to_string();
+}
+
 // ----------------------------------------------------------------
 // METHODS yaml methods
 // ----------------------------------------------------------------
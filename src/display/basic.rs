@@ -2,7 +2,19 @@
 // IMPORTS
 // ----------------------------------------------------------------
 
-//
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::app::bench::BenchResult;
+use crate::app::diagnosis;
+use crate::app::diagnosis::Diagnosis;
+use crate::app::knowledge::Knowledge;
+use crate::app::patterns;
+use crate::app::stats::GameStatistics;
+use crate::app::tree::DecisionTree;
+use crate::setup::config::ConfigParams;
+use crate::app::states::WordlState;
+use crate::core::console;
 
 // ----------------------------------------------------------------
 // print wordle
@@ -17,30 +29,241 @@ pub fn display_word(word: &String) -> String {
     return repr;
 }
 
+/// representation of a guess with its feedback
+/// (falls back to the anonymised feedback, if colours are turned off).
+pub fn display_state(state: &WordlState) -> String {
+    if console::is_coloured() {
+        return state.to_string_with_feedback();
+    }
+    return format!("{} {}", state.to_string(), state.to_string_with_feedback_anon());
+}
+
+/// prints what is known about the solution from all guesses so far
+pub fn display_knowledge(knowledge: &Knowledge) {
+    println!("{}", console::paint(&format!("\n\x1b[2m{}\x1b[0m", knowledge.to_string())));
+}
+
+/// displays the result of a game together with the (anonymised) grid for sharing
+///
+/// ## Arguments ##
+///
+/// - `title` - title of the game, e.g. `Wordle #123`
+/// - `history` - states of the guesses made
+/// - `max_guesses` - number of attempts permitted
+/// - `hard_mode` - whether the game was played in hard mode (marked by `*`)
+pub fn display_share(title: &String, history: &Vec<WordlState>, max_guesses: usize, hard_mode: bool) {
+    let solved = history.last().map(|state| state.is_correct()).unwrap_or(false);
+    let score = if solved { history.len().to_string() } else { String::from("X") };
+    let hard = if hard_mode { "*" } else { "" };
+    println!("\n{} {}/{}{}\n", title, score, max_guesses, hard);
+    for state in history.iter() {
+        println!("{}", state.to_string_with_feedback_anon());
+    }
+    println!("");
+}
+
 // ----------------------------------------------------------------
 // print word list
 // ----------------------------------------------------------------
 
-/// displays a selection of word
+/// displays a selection of suggested guesses
 ///
 /// ## Arguments ##
 ///
-/// - `words` - list of chosen words to be desplayed
-/// - `n_remaining` - length of remaining list of words (NOTE: in general ≥ words.len())
+/// - `guesses` - list of suggested guesses, sorted by best first
+/// - `words_remaining` - list of remaining possible solutions
+/// - `probabilities` - probability of each remaining word being the solution (may be empty)
 /// - `max_length` - maximum number of words to display
 ///
 /// ## Returns ##
 ///
-/// Prints list of words to console with formatting.
-pub fn display_words(words: &Vec<String>, n_remaining: usize, max_length: usize) {
-    println!("\n\x1b[4mCurrent best options ({} remaining):\x1b[0m\n", n_remaining);
-    for (index, word) in words.iter().enumerate() {
+/// Prints list of guesses to console with formatting,
+/// marking those guesses, which cannot be the solution.
+pub fn display_suggestions(guesses: &Vec<String>, words_remaining: &Vec<String>, probabilities: &HashMap<String, f64>, max_length: usize) {
+    println!("{}", console::paint(&format!("\n\x1b[4mCurrent best options ({} remaining):\x1b[0m\n", words_remaining.len())));
+    for (index, word) in guesses.iter().enumerate() {
         if index >= max_length {
             break;
         }
-        println!("  \x1b[2m{}\x1b[0m", word);
+        if words_remaining.contains(word) {
+            println!("{}", console::paint(&format!("  \x1b[2m{}\x1b[0m{}", word, display_probability(probabilities.get(word)))));
+        } else {
+            println!("{}", console::paint(&format!("  \x1b[2m{}\x1b[0m  \x1b[2;3m(not a possible solution)\x1b[0m", word)));
+        }
     }
-    if n_remaining > max_length {
+    if guesses.len() > max_length {
         println!("  ...");
     }
 }
+
+fn display_probability(probability: Option<&f64>) -> String {
+    return match probability {
+        Some(p) => format!("  {:>5.1}%", 100. * p),
+        None => String::from(""),
+    };
+}
+
+// ----------------------------------------------------------------
+// print diagnosis
+// ----------------------------------------------------------------
+
+/// explains, why no words remain, and which feedback to correct
+pub fn display_diagnosis(diagnosis: &Diagnosis, history: &Vec<WordlState>, max_length: usize) {
+    match diagnosis {
+        Diagnosis::Contradiction { turns, reason, fixes, minimal } => {
+            println!("{}", console::paint(&format!("\nThe feedback contradicts itself, as {}:\n", reason)));
+            for &t in turns.iter() {
+                println!("  {:>3}. {}", t + 1, display_state(&history[t]));
+            }
+            if !minimal {
+                println!("{}", console::paint(&format!(
+                    "\n\x1b[2m(No set of at most {} guesses contradicts itself, so all guesses are listed.)\x1b[0m",
+                    diagnosis::MAX_TURNS_IN_CONTRADICTION,
+                )));
+            }
+            // recommend the most likely fix:
+            match fixes.get(0) {
+                Some(&(t, n)) => {
+                    println!("{}", console::paint(&format!("\nCheck the feedback of guess \x1b[1m{}\x1b[0m (without it {} word(s) would remain).", t + 1, n)));
+                },
+                None => {
+                    println!("\nCheck the feedback of the guesses listed above.");
+                },
+            }
+        },
+        Diagnosis::MissingWord { candidates } => {
+            println!("\nThe feedback is consistent, so the solution is probably missing from the list of solutions.");
+            if candidates.len() > 0 {
+                let shown = candidates.iter().take(max_length).cloned().collect::<Vec<String>>();
+                println!("Permitted guesses, which fit the feedback ({} in total): {}", candidates.len(), shown.join(", "));
+            }
+        },
+    }
+}
+
+// ----------------------------------------------------------------
+// print benchmarks
+// ----------------------------------------------------------------
+
+/// displays the summary of a benchmark
+pub fn display_benchmark(result: &BenchResult) {
+    let start = result.start.clone().unwrap_or(String::from("(chosen by tactic)"));
+    let worst = result.worst().map(|n| n.to_string()).unwrap_or(String::from("aborted"));
+    println!("{}", console::paint(&format!("\n\x1b[4mTactic \x1b[1m{}\x1b[0m\x1b[4m, first guess \x1b[1m{}\x1b[0m\n", result.tactic.name(), start)));
+    println!("  games:       {}", result.len());
+    println!("  mean:        {:.4}", result.mean());
+    println!("  worst case:  {}", worst);
+    println!("  failures:    {} (more than {} guesses)", result.failures(), result.max_guesses);
+    println!("  time:        {:.2}s", result.duration.as_secs_f64());
+    println!("  distribution:");
+    for (n, count) in result.distribution().iter() {
+        let bar = "#".repeat((50 * count + result.len() - 1) / result.len().max(1));
+        println!("{}", console::paint(&format!("    {:>3}: {:>6}  \x1b[2m{}\x1b[0m", n, count, bar)));
+    }
+}
+
+// ----------------------------------------------------------------
+// print dictionaries
+// ----------------------------------------------------------------
+
+/// displays a bundled word list with its size and the number of words of each length
+pub fn display_dictionary(name: &str, filename: &str, description: &str, n_words: usize, lengths: &BTreeMap<usize, usize>) {
+    let lengths = lengths.iter()
+        .map(|(length, count)| format!("{} letters: {}", length, count))
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!("{}", console::paint(&format!("\x1b[1m{:<6}\x1b[0m {:>6} words  \x1b[2m({}; {})\x1b[0m", name, n_words, description, filename)));
+    println!("       {}", lengths);
+}
+
+// ----------------------------------------------------------------
+// print config
+// ----------------------------------------------------------------
+
+/// displays the settings in effect and their sources
+/// (`default`, path of the config file, `$WORDLE_…` or a command line option)
+pub fn display_config(config: &ConfigParams) {
+    let settings = config.settings();
+    let width = settings.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    let width_value = settings.iter().map(|(_, value)| value.chars().count()).max().unwrap_or(0);
+    println!("{}", console::paint("\n\x1b[4mSettings\x1b[0m\n"));
+    for (key, value) in settings.iter() {
+        println!("{}", console::paint(&format!(
+            "  {:<width$}  {:<width_value$}  \x1b[2m({})\x1b[0m",
+            format!("{}:", key), value, config.source_of(key), width = width + 1, width_value = width_value,
+        )));
+    }
+}
+
+// ----------------------------------------------------------------
+// print statistics
+// ----------------------------------------------------------------
+
+/// displays the statistics of one kind of game
+pub fn display_statistics(mode: &str, stats: &GameStatistics) {
+    println!("{}", console::paint(&format!("\n\x1b[4mStatistics (\x1b[1m{}\x1b[0m\x1b[4m)\x1b[0m\n", mode)));
+    println!("  played:          {}", stats.played);
+    println!("  won:             {:.0}%", stats.win_percentage());
+    println!("  current streak:  {}", stats.current_streak);
+    println!("  max streak:      {}", stats.max_streak);
+    println!("  guess distribution:");
+    let max_count = stats.distribution.values().cloned().max().unwrap_or(1);
+    for (n, count) in stats.distribution.iter() {
+        let bar = "#".repeat((30 * count + max_count - 1)/max_count);
+        println!("{}", console::paint(&format!("    {:>3}: {:>6}  \x1b[2m{}\x1b[0m", n, count, bar)));
+    }
+}
+
+// ----------------------------------------------------------------
+// print decision trees
+// ----------------------------------------------------------------
+
+/// represents a decision tree in the indented text format common in the community.
+///
+/// Every line is the path to one solution, consisting of guesses, each followed by
+/// its feedback (`B` = incorrect, `Y` = partially correct, `G` = correct) and the number of the guess.
+/// The part each line shares with the previous one is replaced by spaces, e.g.
+///
+/// ```text
+/// salet BBBBB1 courd BBBBB2 nymph GGGGG3
+///                           whiff BBBBB3 ...
+/// ```
+pub fn display_tree_text(tree: &DecisionTree) -> String {
+    let mut paths = Vec::<Vec<String>>::new();
+    collect_tree_paths(tree, &mut vec![], &mut paths);
+    let mut lines = Vec::<String>::new();
+    let mut previous = Vec::<String>::new();
+    for path in paths.iter() {
+        let shared = path.iter().zip(previous.iter()).take_while(|(a, b)| a == b).count();
+        let indent = path[..shared].iter().map(|token| token.chars().count() + 1).sum::<usize>();
+        lines.push(format!("{}{}", " ".repeat(indent), path[shared..].join(" ")));
+        previous = path.clone();
+    }
+    return lines.join("\n");
+}
+
+fn collect_tree_paths(tree: &DecisionTree, path: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    let size_of_word = tree.guess.chars().count();
+    let turn = path.len()/2 + 1;
+    // the guess itself is a solution, if it is not accounted for by any branch:
+    let remaining_in_branches = tree.branches.values().map(|branch| branch.remaining).sum::<usize>();
+    path.push(tree.guess.clone());
+    if tree.remaining > remaining_in_branches {
+        path.push(format!("{}{}", pattern_to_colours(patterns::pattern_correct(size_of_word), size_of_word), turn));
+        paths.push(path.clone());
+        path.pop();
+    }
+    for (&pattern, branch) in tree.branches.iter() {
+        path.push(format!("{}{}", pattern_to_colours(pattern, size_of_word), turn));
+        collect_tree_paths(branch, path, paths);
+        path.pop();
+    }
+    path.pop();
+}
+
+fn pattern_to_colours(pattern: patterns::Pattern, size_of_word: usize) -> String {
+    return patterns::pattern_to_feedback(pattern, size_of_word)
+        .replace("1", "G")
+        .replace("-", "Y")
+        .replace("x", "B");
+}
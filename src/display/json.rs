@@ -0,0 +1,167 @@
+// ----------------------------------------------------------------
+// IMPORTS
+// ----------------------------------------------------------------
+
+extern crate serde_json;
+
+use std::collections::HashMap;
+
+use self::serde_json::json;
+use self::serde_json::Value;
+
+use crate::app::patterns;
+use crate::app::states::WordlConstraint;
+use crate::app::states::WordlState;
+use crate::app::tactics::selection::Tactic;
+use crate::app::tree::DecisionTree;
+
+// ----------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------
+
+/// Version of the schema below. Only to be increased upon breaking changes.
+pub static SCHEMA_VERSION: i32 = 1;
+
+// ----------------------------------------------------------------
+// print suggestions as json
+// ----------------------------------------------------------------
+
+/// serialises suggestions and state as json
+///
+/// ## Schema ##
+///
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "tactic": "<name of tactic>",
+///   "score": "<name of score>",
+///   "remaining": <number of remaining possible solutions>,
+///   "history": [
+///     {
+///       "guess": "<word>",
+///       "feedback": "<symbols 1, -, x>",
+///       "pattern": <base-3 encoding of feedback>,
+///       "constraints": [
+///         {
+///           "symbol": "<letter>",
+///           "indexes": [<indexes where letter must occur>],
+///           "non_indexes": [<indexes where letter must not occur>],
+///           "contains_min": <minimal number of occurrences>,
+///           "bounded": <whether contains_min is also the maximal number of occurrences>
+///         }, ...
+///       ]
+///     }, ...
+///   ],
+///   "suggestions": [
+///     {
+///       "rank": 1,
+///       "word": "<word>",
+///       "score": <score>,
+///       "possible_solution": <bool>,
+///       "probability": <probability of being the solution, null if not a possible solution>
+///     }, ...
+///   ]
+/// }
+/// ```
+///
+/// ## Arguments ##
+///
+/// - `tactic` - the tactic used to rank the suggestions
+/// - `history` - states of previous guesses
+/// - `suggestions` - list of suggestions (best first) to be serialised
+/// - `scores` - scores of the suggestions
+/// - `words_remaining` - list of remaining possible solutions
+/// - `probabilities` - probability of each remaining word being the solution
+pub fn display_suggestions_json(
+    tactic: &Tactic,
+    history: &Vec<WordlState>,
+    suggestions: &Vec<String>,
+    scores: &HashMap<String, f64>,
+    words_remaining: &Vec<String>,
+    probabilities: &HashMap<String, f64>,
+) -> String {
+    let value = json!({
+        "schema_version": SCHEMA_VERSION,
+        "tactic": tactic.name(),
+        "score": tactic.score_name(),
+        "remaining": words_remaining.len(),
+        "history": history.iter().map(state_to_json).collect::<Vec<Value>>(),
+        "suggestions": suggestions.iter()
+            .enumerate()
+            .map(|(index, word)| json!({
+                "rank": index + 1,
+                "word": word,
+                "score": scores.get(word),
+                "possible_solution": words_remaining.contains(word),
+                "probability": probabilities.get(word),
+            }))
+            .collect::<Vec<Value>>(),
+    });
+    return serde_json::to_string_pretty(&value).unwrap();
+}
+
+pub fn state_to_json(state: &WordlState) -> Value {
+    let pattern = state.to_pattern();
+    return json!({
+        "guess": state.to_word(),
+        "feedback": patterns::pattern_to_feedback(pattern, state.len()),
+        "pattern": pattern,
+        "constraints": state.constraints.iter().map(constraint_to_json).collect::<Vec<Value>>(),
+    });
+}
+
+pub fn constraint_to_json(constraint: &WordlConstraint) -> Value {
+    return json!({
+        "symbol": constraint.symbol,
+        "indexes": constraint.indexes,
+        "non_indexes": constraint.non_indexes,
+        "contains_min": constraint.contains_min,
+        "bounded": constraint.bounded,
+    });
+}
+
+// ----------------------------------------------------------------
+// print decision trees as json
+// ----------------------------------------------------------------
+
+/// serialises a decision tree as json (can be loaded again via `app::tree::load_tree`)
+///
+/// ## Schema ##
+///
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "tactic": "<name of tactic used to build the tree>",
+///   "depth": <number of guesses needed in the worst case>,
+///   "total_guesses": <number of guesses needed summed over all solutions>,
+///   "tree": {
+///     "guess": "<word>",
+///     "remaining": <number of possible solutions before the guess>,
+///     "branches": {
+///       "<feedback (symbols 1, -, x)>": { "guess": ..., "remaining": ..., "branches": ... }, ...
+///     }
+///   }
+/// }
+/// ```
+pub fn display_tree_json(tactic: &Tactic, tree: &DecisionTree) -> String {
+    let value = json!({
+        "schema_version": SCHEMA_VERSION,
+        "tactic": tactic.name(),
+        "depth": tree.depth(),
+        "total_guesses": tree.total_guesses(),
+        "tree": tree_to_json(tree),
+    });
+    return serde_json::to_string_pretty(&value).unwrap();
+}
+
+pub fn tree_to_json(tree: &DecisionTree) -> Value {
+    let size_of_word = tree.guess.chars().count();
+    let branches = tree.branches.iter()
+        .map(|(&pattern, branch)| (patterns::pattern_to_feedback(pattern, size_of_word), tree_to_json(branch)))
+        .collect::<serde_json::Map<String, Value>>();
+    return json!({
+        "guess": tree.guess,
+        "remaining": tree.remaining,
+        "branches": branches,
+    });
+}
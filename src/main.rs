@@ -5,6 +5,9 @@
 extern crate wordle;
 
 use wordle::app;
+use wordle::app::tactics::selection::Tactic;
+use wordle::cli;
+use wordle::core::console;
 use wordle::setup;
 
 // ----------------------------------------------------------------
@@ -12,16 +15,232 @@ use wordle::setup;
 // ----------------------------------------------------------------
 
 fn main() {
+    // get arguments
+    let args = cli::args::construct_arg_parser();
+    console::set_coloured(!args.quiet);
     // get assets
     let version = setup::assets::get_version();
-    let words = setup::assets::get_data()
-        .unwrap_or_else(|err| panic!("{}", err));
+    let words = if args.path == "" {
+        setup::assets::get_data()
+    } else {
+        setup::assets::get_data_from_path(&args.path)
+    }.unwrap_or_else(|err| exit_with_error(&format!("Could not read list of words: {}", err)));
+    if !(args.dict == "") && !(args.path_solutions == "") {
+        exit_with_error("Choose the list of solutions either by --dict or by --solutions.");
+    }
+    let solutions = if !(args.path_solutions == "") {
+        setup::assets::get_data_from_path(&args.path_solutions)
+    } else if !(args.dict == "") {
+        setup::assets::get_dictionary_or_path(&args.dict)
+    } else {
+        setup::assets::get_solutions()
+    }.unwrap_or_else(|err| exit_with_error(&format!("Could not read list of solutions: {}", err)));
     let spec = setup::assets::get_config()
-        .unwrap_or_else(|err| panic!("{}", err));
-    // set config
-    let config = setup::config::set_config(&spec, &version);
+        .unwrap_or_else(|err| exit_with_error(&format!("Could not read settings: {}", err)));
+    // set config (defaults < config file < environment < command line)
+    let mut layers = setup::config::get_layers(spec, &args.path_config)
+        .unwrap_or_else(|err| exit_with_config_errors(&vec![err]));
+    if !(args.tactic == "") {
+        layers.push(setup::config::ConfigLayer::from_setting("--tactic", "tactic", &args.tactic));
+    }
+    if args.length > 0 {
+        layers.push(setup::config::ConfigLayer::from_setting("--length", "size-of-wordle", &args.length.to_string()));
+    }
+    if !(args.path_frequencies == "") {
+        layers.push(setup::config::ConfigLayer::from_setting("--frequencies", "frequencies", &args.path_frequencies));
+    }
+    let mut config = setup::config::set_config(&layers, &version)
+        .unwrap_or_else(|errors| exit_with_config_errors(&errors));
+    let (_, solutions_) = setup::assets::get_word_lists(&words, &solutions, config.size_of_wordle);
+    if solutions_.len() == 0 {
+        exit_with_error(&format!("The word lists contain no words of length {} (see command dicts, or use --dict <file>).", config.size_of_wordle));
+    }
+    // otherwise use frequencies stored next to the list of solutions (`<list>.freq`), if any:
+    let path_solutions = if !(args.path_solutions == "") { args.path_solutions.clone() } else { args.dict.clone() };
+    if config.frequencies == "" && std::path::Path::new(&path_solutions).is_file() {
+        let path = std::path::Path::new(&path_solutions).with_extension("freq");
+        if path.is_file() {
+            config.frequencies = path.to_string_lossy().to_string();
+            config.sources.insert(String::from("frequencies"), format!("next to {}", path_solutions));
+        }
+    }
+    let priors = if config.frequencies == "" {
+        app::priors::Priors::uniform()
+    } else {
+        app::priors::Priors::from_path(&config.frequencies)
+            .unwrap_or_else(|err| exit_with_error(&format!("Could not read frequencies: {}", err)))
+    };
+    let format = cli::args::OutputFormat::from_name(&args.format)
+        .unwrap_or_else(|| exit_with_error(&format!("Unknown format `{}`!", args.format)));
+    if args.boards == 0 || args.boards > 1 && !(args.interactive && args.command == "") {
+        exit_with_error("Multiple boards (--boards) are only supported by the interactive solver (--it).");
+    }
+    if args.resume && (args.boards > 1 || !(args.command == "")) {
+        exit_with_error("Only sessions of the interactive solver (single board) can be resumed (--resume).");
+    }
+    let session = if args.resume {
+        // (the interactive solver continues with the tactic of the session)
+        let session = app::session::Session::load(&config.data_directory)
+            .unwrap_or_else(|err| exit_with_error(&err));
+        if session.is_none() {
+            eprintln!("{}", console::paint("\x1b[2mNo session to resume, starting a new one.\x1b[0m"));
+        }
+        session
+    } else {
+        if args.interactive && args.boards == 1 && args.command == "" && app::session::Session::exists(&config.data_directory) {
+            eprintln!("{}", console::paint("[\x1b[93;1mWARNING\x1b[0m] An unfinished session is saved and will be replaced after the first guess (use --resume to carry on with it)."));
+        }
+        None
+    };
     // run methods
-    app::menus::show_start_screen(&config);
-    app::menus::main_menu(&config, &words);
-    app::menus::show_end_screen(&config);
+    match args.command.as_str() {
+        "" => {
+            if args.interactive || args.resume {
+                if !args.quiet {
+                    app::menus::show_start_screen(&config);
+                }
+                let tree = if args.path_tree == "" {
+                    None
+                } else {
+                    // the tree must have been built for the current word length and lists:
+                    let (guesses, _) = setup::assets::get_word_lists(&words, &solutions, config.size_of_wordle);
+                    let tree = app::tree::load_tree(&args.path_tree).unwrap_or_else(|err| exit_with_error(&err));
+                    tree.check_words(config.size_of_wordle, &guesses)
+                        .unwrap_or_else(|err| exit_with_error(&format!("Decision tree `{}` does not fit the word lists: {}.", args.path_tree, err)));
+                    Some(tree)
+                };
+                if args.boards > 1 {
+                    app::menus::multi_board_menu(&config, &words, &solutions, &priors, args.boards);
+                } else {
+                    app::menus::main_menu(&config, &words, &solutions, &priors, &tree, session);
+                }
+                if !args.quiet {
+                    app::menus::show_end_screen(&config);
+                }
+            } else {
+                app::menus::show_suggestions(&config, &words, &solutions, &priors, &vec![], &format);
+            }
+        },
+        "suggest" => {
+            let arguments = cli::args::construct_suggest_parser(&args.arguments);
+            let history = app::solver::parse_history(&arguments.history, config.size_of_wordle)
+                .unwrap_or_else(|err| exit_with_error(&err));
+            app::menus::show_suggestions(&config, &words, &solutions, &priors, &history, &format);
+        },
+        "tree" => {
+            let arguments = cli::args::construct_tree_parser(&args.arguments);
+            let start = if arguments.start == "" {
+                None
+            } else {
+                if !(arguments.start.chars().count() == config.size_of_wordle) {
+                    exit_with_error(&format!("Length of opening word `{}` must be {}.", arguments.start, config.size_of_wordle));
+                }
+                Some(arguments.start.to_lowercase())
+            };
+            let depth = if arguments.depth == 0 { config.max_guesses } else { arguments.depth };
+            let output = if arguments.output == "" { None } else { Some(arguments.output.clone()) };
+            app::menus::show_tree(&config, &words, &solutions, &priors, &start, arguments.breadth, depth, &output, &format)
+                .unwrap_or_else(|err| exit_with_error(&err));
+        },
+        "play" => {
+            let arguments = cli::args::construct_play_parser(&args.arguments);
+            let seed = if arguments.seed == "" {
+                None
+            } else {
+                Some(arguments.seed.parse::<u64>()
+                    .unwrap_or_else(|_| exit_with_error(&format!("Seed `{}` must be a non-negative integer.", arguments.seed))))
+            };
+            if arguments.hard {
+                config.hard_mode = true;
+                config.sources.insert(String::from("hard-mode"), String::from("--hard"));
+            }
+            if !args.quiet {
+                app::menus::show_start_screen(&config);
+            }
+            app::menus::play_menu(&config, &words, &solutions, seed);
+            if !args.quiet {
+                app::menus::show_end_screen(&config);
+            }
+        },
+        "daily" => {
+            if !args.quiet {
+                app::menus::show_start_screen(&config);
+            }
+            app::menus::daily_menu(&config, &words, &solutions)
+                .unwrap_or_else(|err| exit_with_error(&err));
+            if !args.quiet {
+                app::menus::show_end_screen(&config);
+            }
+        },
+        "absurdle" => {
+            if !args.quiet {
+                app::menus::show_start_screen(&config);
+            }
+            app::menus::absurdle_menu(&config, &words, &solutions);
+            if !args.quiet {
+                app::menus::show_end_screen(&config);
+            }
+        },
+        "stats" => {
+            app::menus::show_statistics(&config)
+                .unwrap_or_else(|err| exit_with_error(&err));
+        },
+        "config" => {
+            let arguments = cli::args::construct_config_parser(&args.arguments);
+            match arguments.action.as_str() {
+                "show" => { app::menus::show_config(&config); },
+                // invalid settings have already ended the programme:
+                "check" => { app::menus::show_config_check(&layers); },
+                action => { exit_with_error(&format!("Unknown action `{}` of command `config`!", action)); },
+            }
+        },
+        "dicts" => {
+            app::menus::show_dictionaries()
+                .unwrap_or_else(|err| exit_with_error(&err));
+        },
+        "bench" => {
+            let arguments = cli::args::construct_bench_parser(&args.arguments);
+            let tactics = if arguments.tactics.len() == 0 {
+                Tactic::all()
+            } else {
+                arguments.tactics.iter()
+                    .map(|name| Tactic::from_name(name)
+                        .unwrap_or_else(|| exit_with_error(&format!("Unknown tactic `{}`!", name))))
+                    .collect::<Vec<Tactic>>()
+            };
+            let starts = if arguments.starts.len() == 0 {
+                vec![None]
+            } else {
+                arguments.starts.iter()
+                    .map(|word| {
+                        if !(word.chars().count() == config.size_of_wordle) {
+                            exit_with_error(&format!("Length of first guess `{}` must be {}.", word, config.size_of_wordle));
+                        }
+                        return Some(word.to_lowercase());
+                    })
+                    .collect::<Vec<Option<String>>>()
+            };
+            app::menus::show_benchmarks(&config, &words, &solutions, &priors, &tactics, &starts, arguments.limit);
+        },
+        command => {
+            exit_with_error(&format!("Unknown command `{}`!", command));
+        },
+    }
+}
+
+// ----------------------------------------------------------------
+// AUXILIARY METHODS
+// ----------------------------------------------------------------
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", console::paint(&format!("[\x1b[91mERROR\x1b[0m] {}", message)));
+    std::process::exit(1);
+}
+
+fn exit_with_config_errors(errors: &Vec<setup::config::ConfigError>) -> ! {
+    eprintln!("{}", console::paint("[\x1b[91mERROR\x1b[0m] Invalid settings:"));
+    for err in errors.iter() {
+        eprintln!("  - {}", err);
+    }
+    std::process::exit(1);
 }
@@ -6,6 +6,9 @@ extern crate rust_embed;
 extern crate yaml_rust;
 
 use std::io;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
 
 use self::rust_embed::RustEmbed;
 use self::yaml_rust::Yaml;
@@ -18,7 +21,7 @@ use crate::core::utils;
 
 #[derive(RustEmbed)]
 #[folder = "assets"]
-#[include = "data.txt"]
+#[include = "*.txt"]
 struct AssetsData;
 
 #[derive(RustEmbed)]
@@ -33,17 +36,96 @@ struct AssetsVersion;
 
 
 
+// ----------------------------------------------------------------
+// Dictionaries
+// ----------------------------------------------------------------
+
+/// Word lists embedded in the programme: (name, file in `assets`, description).
+pub static DICTIONARIES: &[(&str, &str, &str)] = &[
+    ("full", "words.txt", "all allowed guesses"),
+    ("nyt", "words_nyt.txt", "solutions of the New York Times"),
+];
+
+/// name of the bundled list of allowed guesses
+pub static DICTIONARY_GUESSES: &str = "full";
+
+/// name of the bundled list of solutions used, unless another is chosen
+pub static DICTIONARY_SOLUTIONS: &str = "nyt";
+
 // ----------------------------------------------------------------
 // Methods - get data
 // ----------------------------------------------------------------
 
+/// gets a bundled list of words by name (see `DICTIONARIES`)
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::setup::assets::get_dictionary;
+/// assert_eq!(get_dictionary("nyt").unwrap().len(), 2309);
+/// assert!(get_dictionary("klingon").is_err());
+/// ```
+pub fn get_dictionary(name: &str) -> Result<Vec<String>, io::Error> {
+    let &(_, filename, _) = DICTIONARIES.iter()
+        .find(|&&(name_, _, _)| name_ == name)
+        .ok_or_else(|| io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Unknown dictionary `{}` (bundled: {}).", name, get_dictionary_names().join(", ")),
+        ))?;
+    let file = AssetsData::get(filename)
+        .ok_or_else(|| io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("The bundled list `{}` of dictionary `{}` is missing.", filename, name),
+        ))?;
+    return utils::read_from_embedded_file(file)
+        .and_then(|contents| Ok(read_words(&utils::read_contents_to_lines(&contents, true))));
+}
+
+/// gets a bundled list of words, if the argument is the name of one, otherwise reads the list from the path
+pub fn get_dictionary_or_path(name_or_path: &str) -> Result<Vec<String>, io::Error> {
+    if get_dictionary_names().contains(&name_or_path.to_string()) {
+        return get_dictionary(name_or_path);
+    } else if Path::new(name_or_path).exists() {
+        return get_data_from_path(name_or_path);
+    }
+    return Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("`{}` is neither a bundled dictionary ({}) nor a file.", name_or_path, get_dictionary_names().join(", ")),
+    ));
+}
+
+pub fn get_dictionary_names() -> Vec<String> {
+    return DICTIONARIES.iter()
+        .map(|&(name, _, _)| name.to_string())
+        .collect::<Vec<String>>();
+}
+
+/// gets the list of allowed guesses
 pub fn get_data() -> Result<Vec<String>, io::Error> {
-    return utils::read_from_embedded_file(AssetsData::get("words.txt").unwrap())
-        .and_then(|contents| Ok(read_words(&contents)));
+    return get_dictionary(DICTIONARY_GUESSES);
 }
 
-pub fn read_words(contents: &String) -> Vec<String> {
-    let lines = utils::read_contents_to_lines(&contents, true);
+/// gets the list of possible solutions
+pub fn get_solutions() -> Result<Vec<String>, io::Error> {
+    return get_dictionary(DICTIONARY_SOLUTIONS);
+}
+
+/// counts the words of each length
+pub fn count_lengths(words: &Vec<String>) -> BTreeMap<usize, usize> {
+    let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+    for word in words.iter() {
+        *counts.entry(utils::length_of_word(word)).or_insert(0) += 1;
+    }
+    return counts;
+}
+
+/// gets a list of words from a file (one word per line)
+pub fn get_data_from_path(path: &str) -> Result<Vec<String>, io::Error> {
+    return utils::read_file_to_lines(path, true)
+        .and_then(|lines| Ok(read_words(&lines)));
+}
+
+pub fn read_words(lines: &Vec<String>) -> Vec<String> {
     let matcher = utils::construct_regex(r"^\w+$");
     let mut words = Vec::<String>::new();
     for line in lines {
@@ -55,12 +137,60 @@ pub fn read_words(contents: &String) -> Vec<String> {
     return words;
 }
 
+/// restricts the lists of guesses and solutions to words of the appropriate size
+///
+/// ## Arguments ##
+///
+/// - `words` - list of allowed guesses
+/// - `solutions` - list of possible solutions
+/// - `size_of_wordle` - length of words
+///
+/// ## Returns ##
+///
+/// `(guesses, solutions)`, where every solution is also an allowed guess.
+/// If no solutions of the appropriate size exist, the guesses serve as solutions.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::setup::assets::get_word_lists;
+/// let words = vec![String::from("crane"), String::from("cat"), String::from("react")];
+/// let solutions = vec![String::from("react"), String::from("trace")];
+/// let (guesses, solutions) = get_word_lists(&words, &solutions, 5);
+/// assert_eq!(guesses, vec!["crane", "react", "trace"]);
+/// assert_eq!(solutions, vec!["react", "trace"]);
+/// let (guesses, solutions) = get_word_lists(&words, &vec![], 3);
+/// assert_eq!((guesses.len(), solutions.len()), (1, 1));
+/// ```
+pub fn get_word_lists(words: &Vec<String>, solutions: &Vec<String>, size_of_wordle: usize) -> (Vec<String>, Vec<String>) {
+    let mut guesses = words.iter()
+        .filter(|&word| utils::length_of_word(word) == size_of_wordle)
+        .cloned()
+        .collect::<Vec<String>>();
+    let mut solutions = solutions.iter()
+        .filter(|&word| utils::length_of_word(word) == size_of_wordle)
+        .cloned()
+        .collect::<Vec<String>>();
+    if solutions.len() == 0 {
+        solutions = guesses.clone();
+    }
+    let known = guesses.iter().cloned().collect::<HashSet<String>>();
+    for word in solutions.iter() {
+        if !known.contains(word) {
+            guesses.push(word.clone());
+        }
+    }
+    return (guesses, solutions);
+}
+
 // ----------------------------------------------------------------
 // Methods - get config
 // ----------------------------------------------------------------
 
 pub fn get_config() -> Result<Yaml, io::Error> {
-    return utils::read_from_embedded_file(AssetsConfig::get("config.yml").unwrap())
+    let file = AssetsConfig::get("config.yml")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "The bundled config is missing."))?;
+    return utils::read_from_embedded_file(file)
         .and_then(|source| {
             return utils::read_contents_to_yaml(&source);
         });
@@ -71,7 +201,8 @@ pub fn get_config() -> Result<Yaml, io::Error> {
 // ----------------------------------------------------------------
 
 pub fn get_version() -> String {
-    return utils::read_from_embedded_file(AssetsVersion::get("VERSION").unwrap())
+    return AssetsVersion::get("VERSION")
+        .and_then(|file| utils::read_from_embedded_file(file).ok())
         .map(|contents| contents.trim().to_string())
-        .unwrap_or_else(|_| "x.y.z".to_string());
+        .unwrap_or_else(|| "x.y.z".to_string());
 }
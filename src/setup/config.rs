@@ -4,50 +4,497 @@
 
 extern crate yaml_rust;
 
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
 use self::yaml_rust::Yaml;
+use self::yaml_rust::yaml::Hash;
 
+use crate::core::console;
+use crate::core::dates;
 use crate::core::utils;
+use crate::app::tactics::selection::Tactic;
 
 // ----------------------------------------------------------------
 // Structure
 // ----------------------------------------------------------------
 
+#[derive(Clone)]
 pub struct ConfigParams {
     pub version: String,
     pub title: String,
     pub url: String,
     pub notes: String,
     pub size_of_wordle: usize,
+    pub max_guesses: usize,
     pub max_display_length: usize,
     pub max_length_for_best_optimisation: usize,
+    pub tactic: Tactic,
+    pub data_directory: String,
+    pub cache_feedback_matrix: bool,
+    pub opening_book: bool,
+    pub frequencies: String,
+    pub daily_epoch: String,
+    pub daily_seed: u64,
     pub hard_mode: bool,
     pub anonymous_feedback: bool,
+    /// source of each setting (see `ConfigLayer`), keyed as in the config file
+    pub sources: BTreeMap<String, String>,
+}
+
+/// Settings from one source.
+///
+/// Layers are applied in increasing order of precedence:
+/// embedded defaults, user config file, `WORDLE_*` environment variables, command line.
+pub struct ConfigLayer {
+    pub source: String,
+    pub spec: Yaml,
+}
+
+/// path of the user config file, relative to the config directory (`$XDG_CONFIG_HOME` or `~/.config`)
+pub static PATH_TO_CONFIG: &str = "wordle/config.yml";
+
+/// prefix of environment variables overriding settings
+pub static PREFIX_ENV: &str = "WORDLE_";
+
+/// permitted lengths of words
+pub const MIN_SIZE_OF_WORDLE: usize = 3;
+pub const MAX_SIZE_OF_WORDLE: usize = 12;
+
+/// Problems found in the layers of settings.
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Unreadable { source: String, reason: String },
+    UnknownKey { source: String, key: String },
+    TypeMismatch { source: String, key: String, expected: String },
+    OutOfRange { source: String, key: String, value: String, expected: String },
+    Missing { key: String },
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Text,
+    Boolean,
+    Integer { min: i64, max: i64 },
+    Tactic,
+    Date,
 }
 
-pub static PATH_TO_CONFIG: &str = "src/setup/config.yml";
+/// every permitted key (section, key) and the kind of its value
+static SCHEMA: &[(&str, &str, Kind)] = &[
+    ("info", "title", Kind::Text),
+    ("info", "description", Kind::Text),
+    ("info", "url", Kind::Text),
+    ("info", "notes", Kind::Text),
+    ("settings", "size-of-wordle", Kind::Integer { min: MIN_SIZE_OF_WORDLE as i64, max: MAX_SIZE_OF_WORDLE as i64 }),
+    ("settings", "max-guesses", Kind::Integer { min: 0, max: i64::MAX }),
+    ("settings", "max-display-length", Kind::Integer { min: 1, max: i64::MAX }),
+    ("settings", "max-length-for-best-optimisation", Kind::Integer { min: 0, max: i64::MAX }),
+    ("settings", "tactic", Kind::Tactic),
+    ("settings", "data-directory", Kind::Text),
+    ("settings", "cache-feedback-matrix", Kind::Boolean),
+    ("settings", "daily-epoch", Kind::Date),
+    ("settings", "daily-seed", Kind::Integer { min: 0, max: i64::MAX }),
+    ("settings", "opening-book", Kind::Boolean),
+    ("settings", "frequencies", Kind::Text),
+    ("settings", "hard-mode", Kind::Boolean),
+    ("settings", "anonymous-feedback", Kind::Boolean),
+];
 
 // ----------------------------------------------------------------
-// Prompt confirm
+// Implementation ConfigLayer
 // ----------------------------------------------------------------
 
-pub fn set_config(spec: &Yaml, version: &String) -> ConfigParams {
-    return ConfigParams {
-        version: version.clone(),
-        title:
-            utils::attribute_or_default(spec["info"]["title"].as_str(), "<app name missing>").to_string(),
-        url:
-            utils::attribute_or_default(spec["info"]["url"].as_str(), "<url missing>").to_string(),
-        notes:
-            utils::attribute_or_default(spec["info"]["notes"].as_str(), "").to_string(),
-        size_of_wordle:
-            utils::i64_to_usize(utils::attribute_or_default(spec["settings"]["size-of-wordle"].as_i64(), 4)),
-        max_display_length:
-            utils::i64_to_usize(utils::attribute_or_default(spec["settings"]["max-display-length"].as_i64(), 100)),
-        max_length_for_best_optimisation:
-            utils::i64_to_usize(utils::attribute_or_default(spec["settings"]["max-length-for-best-optimisation"].as_i64(), 500)),
-        hard_mode:
-            utils::attribute_or_default(spec["settings"]["hard-mode"].as_bool(), false),
-        anonymous_feedback:
-            utils::attribute_or_default(spec["settings"]["hard-mode"].as_bool(), true),
+impl ConfigLayer {
+    /// creates a layer setting a single value, which is parsed as yaml (so that numbers and booleans get their type)
+    ///
+    /// ## Examples ##
+    ///
+    /// ```rust
+    /// use wordle::setup::config::ConfigLayer;
+    /// let layer = ConfigLayer::from_setting("--tactic", "tactic", "entropy");
+    /// assert_eq!(layer.spec["settings"]["tactic"].as_str(), Some("entropy"));
+    /// let layer = ConfigLayer::from_setting("$WORDLE_MAX_GUESSES", "max-guesses", "8");
+    /// assert_eq!(layer.spec["settings"]["max-guesses"].as_i64(), Some(8));
+    /// ```
+    pub fn from_setting(source: &str, key: &str, value: &str) -> Self {
+        let value = match utils::read_contents_to_yaml(&value.to_string()) {
+            Ok(Yaml::Hash(_)) | Ok(Yaml::Array(_)) | Err(_) => Yaml::String(value.to_string()),
+            Ok(value) => value,
+        };
+        let mut settings = Hash::new();
+        settings.insert(Yaml::String(key.to_string()), value);
+        let mut spec = Hash::new();
+        spec.insert(Yaml::String(String::from("settings")), Yaml::Hash(settings));
+        return ConfigLayer { source: source.to_string(), spec: Yaml::Hash(spec) };
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods - layers
+// ----------------------------------------------------------------
+
+pub fn get_path_to_user_config() -> String {
+    let directory = match std::env::var("XDG_CONFIG_HOME") {
+        Ok(path) if !(path == "") => path,
+        _ => utils::expand_home_directory("~/.config"),
+    };
+    return Path::new(&directory).join(PATH_TO_CONFIG).to_string_lossy().to_string();
+}
+
+/// creates a layer for each environment variable of the form `WORDLE_<KEY>`,
+/// where `<KEY>` is the key of a setting in upper case with `_` instead of `-`.
+/// Other variables starting with `WORDLE_` (e.g. of other tools) are ignored with a warning.
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::setup::config::layers_from_env;
+/// let vars = vec![
+///     (String::from("HOME"), String::from("/home/me")),
+///     (String::from("WORDLE_SIZE_OF_WORDLE"), String::from("6")),
+///     (String::from("WORDLE_SERVER_TOKEN"), String::from("secret")),
+/// ];
+/// let layers = layers_from_env(vars.into_iter());
+/// assert_eq!(layers.len(), 1);
+/// assert_eq!(layers[0].source, "$WORDLE_SIZE_OF_WORDLE");
+/// assert_eq!(layers[0].spec["settings"]["size-of-wordle"].as_i64(), Some(6));
+/// ```
+pub fn layers_from_env<I: Iterator<Item=(String, String)>>(vars: I) -> Vec<ConfigLayer> {
+    let mut layers = vars
+        .filter(|(name, _)| name.starts_with(PREFIX_ENV) && name.len() > PREFIX_ENV.len())
+        .filter_map(|(name, value)| {
+            let key = name[PREFIX_ENV.len()..].to_lowercase().replace("_", "-");
+            if !SCHEMA.iter().any(|&(section, key_, _)| section == "settings" && key_ == key) {
+                eprintln!("{}", console::paint(&format!("[\x1b[93;1mWARNING\x1b[0m] Ignoring ${}, which is not a setting.", name)));
+                return None;
+            }
+            return Some(ConfigLayer::from_setting(&format!("${}", name), &key, &value));
+        })
+        .collect::<Vec<ConfigLayer>>();
+    layers.sort_by(|a, b| a.source.cmp(&b.source));
+    return layers;
+}
+
+/// collects the layers of settings below the command line
+///
+/// ## Arguments ##
+///
+/// - `spec` - embedded defaults
+/// - `path` - path to a user config file (must exist), or empty for the default path (used, if it exists)
+pub fn get_layers(spec: Yaml, path: &str) -> Result<Vec<ConfigLayer>, ConfigError> {
+    let mut layers = vec![ConfigLayer { source: String::from("default"), spec }];
+    let path = if path == "" {
+        let path = get_path_to_user_config();
+        if Path::new(&path).exists() { Some(path) } else { None }
+    } else {
+        Some(path.to_string())
+    };
+    match path {
+        Some(path) => {
+            let spec = utils::read_yaml(&path)
+                .map_err(|err| ConfigError::Unreadable { source: path.clone(), reason: err.to_string() })?;
+            layers.push(ConfigLayer { source: path, spec });
+        },
+        None => { },
+    }
+    layers.extend(layers_from_env(std::env::vars()));
+    return Ok(layers);
+}
+
+/// merges the layers of settings, later layers overriding earlier ones
+fn merge_layers(layers: &Vec<ConfigLayer>) -> (Yaml, BTreeMap<String, String>) {
+    let mut merged = Hash::new();
+    let mut sources: BTreeMap<String, String> = BTreeMap::new();
+    for layer in layers.iter() {
+        let sections = match layer.spec.as_hash() {
+            Some(sections) => sections,
+            None => { continue; },
+        };
+        for (section, entries) in sections.iter() {
+            let entries = match entries.as_hash() {
+                Some(entries) => entries,
+                None => { continue; },
+            };
+            let target = merged.entry(section.clone()).or_insert(Yaml::Hash(Hash::new()));
+            match target {
+                Yaml::Hash(target) => {
+                    for (key, value) in entries.iter() {
+                        target.insert(key.clone(), value.clone());
+                        match (section.as_str(), key.as_str()) {
+                            (Some("settings"), Some(key)) => { sources.insert(key.to_string(), layer.source.clone()); },
+                            _ => { },
+                        }
+                    }
+                },
+                _ => { },
+            }
+        }
+    }
+    return (Yaml::Hash(merged), sources);
+}
+
+// ----------------------------------------------------------------
+// Methods - validation
+// ----------------------------------------------------------------
+
+/// checks every layer against the schema of the config file and that the merged layers set every key
+///
+/// ## Examples ##
+///
+/// ```rust
+/// use wordle::core::utils::read_contents_to_yaml;
+/// use wordle::setup::config::validate_layers;
+/// use wordle::setup::config::ConfigError;
+/// use wordle::setup::config::ConfigLayer;
+/// let spec = read_contents_to_yaml(&String::from("settings: {size-of-wordle: 13, hard-mode: yes, colour: red}")).unwrap();
+/// let errors = validate_layers(&vec![ConfigLayer { source: String::from("my.yml"), spec }]);
+/// let source = String::from("my.yml");
+/// assert!(errors.contains(&ConfigError::OutOfRange {
+///     source: source.clone(),
+///     key: String::from("settings.size-of-wordle"),
+///     value: String::from("13"),
+///     expected: String::from("at most 12"),
+/// }));
+/// assert!(errors.contains(&ConfigError::TypeMismatch {
+///     source: source.clone(),
+///     key: String::from("settings.hard-mode"),
+///     expected: String::from("a boolean (true | false)"),
+/// }));
+/// assert!(errors.contains(&ConfigError::UnknownKey { source: source.clone(), key: String::from("settings.colour") }));
+/// assert!(errors.contains(&ConfigError::Missing { key: String::from("settings.max-guesses") }));
+/// ```
+pub fn validate_layers(layers: &Vec<ConfigLayer>) -> Vec<ConfigError> {
+    let mut errors = Vec::<ConfigError>::new();
+    for layer in layers.iter() {
+        let sections = match layer.spec.as_hash() {
+            Some(sections) => sections,
+            None => {
+                errors.push(ConfigError::Unreadable { source: layer.source.clone(), reason: String::from("expected a mapping of sections") });
+                continue;
+            },
+        };
+        for (section, entries) in sections.iter() {
+            let section = section.as_str().unwrap_or("?");
+            if !SCHEMA.iter().any(|&(section_, _, _)| section_ == section) {
+                errors.push(ConfigError::UnknownKey { source: layer.source.clone(), key: section.to_string() });
+                continue;
+            }
+            let entries = match entries.as_hash() {
+                Some(entries) => entries,
+                None => {
+                    errors.push(ConfigError::TypeMismatch { source: layer.source.clone(), key: section.to_string(), expected: String::from("a mapping") });
+                    continue;
+                },
+            };
+            for (key, value) in entries.iter() {
+                let key = key.as_str().unwrap_or("?");
+                let path = format!("{}.{}", section, key);
+                match SCHEMA.iter().find(|&&(section_, key_, _)| section_ == section && key_ == key) {
+                    Some(&(_, _, kind)) => {
+                        match check_value(kind, value, &layer.source, &path) {
+                            Some(err) => { errors.push(err); },
+                            None => { },
+                        }
+                    },
+                    None => {
+                        errors.push(ConfigError::UnknownKey { source: layer.source.clone(), key: path });
+                    },
+                }
+            }
+        }
+    }
+    let (spec, _) = merge_layers(layers);
+    for &(section, key, _) in SCHEMA.iter() {
+        if spec[section][key].is_badvalue() {
+            errors.push(ConfigError::Missing { key: format!("{}.{}", section, key) });
+        }
+    }
+    return errors;
+}
+
+fn check_value(kind: Kind, value: &Yaml, source: &String, key: &String) -> Option<ConfigError> {
+    let mismatch = |expected: &str| Some(ConfigError::TypeMismatch {
+        source: source.clone(),
+        key: key.clone(),
+        expected: expected.to_string(),
+    });
+    let out_of_range = |value: String, expected: String| Some(ConfigError::OutOfRange {
+        source: source.clone(),
+        key: key.clone(),
+        value,
+        expected,
+    });
+    match kind {
+        Kind::Text => {
+            return if value.as_str().is_some() { None } else { mismatch("a string") };
+        },
+        Kind::Boolean => {
+            return if value.as_bool().is_some() { None } else { mismatch("a boolean (true | false)") };
+        },
+        Kind::Integer { min, max } => {
+            return match value.as_i64() {
+                None => mismatch("an integer"),
+                Some(n) if n < min => out_of_range(n.to_string(), format!("at least {}", min)),
+                Some(n) if n > max => out_of_range(n.to_string(), format!("at most {}", max)),
+                Some(_) => None,
+            };
+        },
+        Kind::Tactic => {
+            return match value.as_str() {
+                None => mismatch("a string"),
+                Some(name) if Tactic::from_name(name).is_none() => {
+                    let names = Tactic::all().iter().map(|tactic| tactic.name()).collect::<Vec<&str>>().join(" | ");
+                    out_of_range(name.to_string(), format!("one of {}", names))
+                },
+                Some(_) => None,
+            };
+        },
+        Kind::Date => {
+            return match value.as_str() {
+                None => mismatch("a date (YYYY-MM-DD)"),
+                Some(text) if dates::parse_date(text).is_none() => out_of_range(text.to_string(), String::from("a date (YYYY-MM-DD)")),
+                Some(_) => None,
+            };
+        },
+    }
+}
+
+// ----------------------------------------------------------------
+// Methods - config
+// ----------------------------------------------------------------
+
+/// validates and merges the layers of settings into the config
+///
+/// ## Examples ##
+///
+/// Every field is read from its own key of the config file:
+///
+/// ```rust
+/// use wordle::setup::assets::get_config;
+/// use wordle::setup::config::set_config;
+/// use wordle::setup::config::ConfigLayer;
+/// let spec = get_config().unwrap();
+/// let layers = vec![
+///     ConfigLayer { source: String::from("default"), spec: spec.clone() },
+///     ConfigLayer::from_setting("--hard-mode", "hard-mode", "true"),
+///     ConfigLayer::from_setting("--anonymous-feedback", "anonymous-feedback", "false"),
+/// ];
+/// let config = set_config(&layers, &String::from("0.0.0")).ok().unwrap();
+/// let settings = &spec["settings"];
+/// assert_eq!(config.version, "0.0.0");
+/// assert_eq!(Some(config.title.as_str()), spec["info"]["title"].as_str());
+/// assert_eq!(Some(config.url.as_str()), spec["info"]["url"].as_str());
+/// assert_eq!(Some(config.notes.as_str()), spec["info"]["notes"].as_str());
+/// assert_eq!(Some(config.size_of_wordle as i64), settings["size-of-wordle"].as_i64());
+/// assert_eq!(settings["max-guesses"].as_i64(), Some(0));
+/// assert_eq!(config.max_guesses, config.size_of_wordle + 1);
+/// assert_eq!(Some(config.max_display_length as i64), settings["max-display-length"].as_i64());
+/// assert_eq!(Some(config.max_length_for_best_optimisation as i64), settings["max-length-for-best-optimisation"].as_i64());
+/// assert_eq!(Some(config.tactic.name()), settings["tactic"].as_str());
+/// assert!(settings["data-directory"].as_str().unwrap().ends_with(".wordle"));
+/// assert!(config.data_directory.ends_with(".wordle"));
+/// assert_eq!(Some(config.cache_feedback_matrix), settings["cache-feedback-matrix"].as_bool());
+/// assert_eq!(Some(config.opening_book), settings["opening-book"].as_bool());
+/// assert_eq!(Some(config.frequencies.as_str()), settings["frequencies"].as_str());
+/// assert_eq!(Some(config.daily_epoch.as_str()), settings["daily-epoch"].as_str());
+/// assert_eq!(Some(config.daily_seed as i64), settings["daily-seed"].as_i64());
+/// assert_eq!((config.hard_mode, config.anonymous_feedback), (true, false));
+/// assert_eq!(config.sources.get("size-of-wordle").unwrap(), "default");
+/// assert_eq!(config.sources.get("anonymous-feedback").unwrap(), "--anonymous-feedback");
+/// ```
+pub fn set_config(layers: &Vec<ConfigLayer>, version: &String) -> Result<ConfigParams, Vec<ConfigError>> {
+    let errors = validate_layers(layers);
+    if errors.len() > 0 {
+        return Err(errors);
+    }
+    // NOTE: the validation guarantees, that every key is present and of the right kind.
+    let (spec, sources) = merge_layers(layers);
+    let info = &spec["info"];
+    let settings = &spec["settings"];
+    let size_of_wordle = utils::i64_to_usize(settings["size-of-wordle"].as_i64().unwrap());
+    // by default one more guess than letters:
+    let max_guesses = match utils::i64_to_usize(settings["max-guesses"].as_i64().unwrap()) {
+        0 => size_of_wordle + 1,
+        n => n,
     };
+    return Ok(ConfigParams {
+        version: version.clone(),
+        title: info["title"].as_str().unwrap().to_string(),
+        url: info["url"].as_str().unwrap().to_string(),
+        notes: info["notes"].as_str().unwrap().to_string(),
+        size_of_wordle,
+        max_guesses,
+        max_display_length: utils::i64_to_usize(settings["max-display-length"].as_i64().unwrap()),
+        max_length_for_best_optimisation: utils::i64_to_usize(settings["max-length-for-best-optimisation"].as_i64().unwrap()),
+        tactic: Tactic::from_name(settings["tactic"].as_str().unwrap()).unwrap(),
+        data_directory: utils::expand_home_directory(settings["data-directory"].as_str().unwrap()),
+        cache_feedback_matrix: settings["cache-feedback-matrix"].as_bool().unwrap(),
+        opening_book: settings["opening-book"].as_bool().unwrap(),
+        frequencies: utils::expand_home_directory(settings["frequencies"].as_str().unwrap()),
+        daily_epoch: settings["daily-epoch"].as_str().unwrap().to_string(),
+        daily_seed: settings["daily-seed"].as_i64().unwrap() as u64,
+        hard_mode: settings["hard-mode"].as_bool().unwrap(),
+        anonymous_feedback: settings["anonymous-feedback"].as_bool().unwrap(),
+        sources,
+    });
+}
+
+// ----------------------------------------------------------------
+// Implementation ConfigParams
+// ----------------------------------------------------------------
+
+impl ConfigParams {
+    /// the values of the settings, keyed as in the config file (in order of the config file)
+    pub fn settings(self: &Self) -> Vec<(String, String)> {
+        return vec![
+            ("size-of-wordle", self.size_of_wordle.to_string()),
+            ("max-guesses", self.max_guesses.to_string()),
+            ("max-display-length", self.max_display_length.to_string()),
+            ("max-length-for-best-optimisation", self.max_length_for_best_optimisation.to_string()),
+            ("tactic", self.tactic.name().to_string()),
+            ("data-directory", self.data_directory.clone()),
+            ("cache-feedback-matrix", self.cache_feedback_matrix.to_string()),
+            ("daily-epoch", self.daily_epoch.clone()),
+            ("daily-seed", self.daily_seed.to_string()),
+            ("opening-book", self.opening_book.to_string()),
+            ("frequencies", self.frequencies.clone()),
+            ("hard-mode", self.hard_mode.to_string()),
+            ("anonymous-feedback", self.anonymous_feedback.to_string()),
+        ].into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect::<Vec<(String, String)>>();
+    }
+
+    /// the source of a setting (`-` if not set by any layer)
+    pub fn source_of(self: &Self, key: &str) -> String {
+        return self.sources.get(key).cloned().unwrap_or(String::from("-"));
+    }
+}
+
+// ----------------------------------------------------------------
+// Implementation ConfigError
+// ----------------------------------------------------------------
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Unreadable { source, reason } => {
+                write!(f, "{}: could not read settings ({}).", source, reason)
+            },
+            ConfigError::UnknownKey { source, key } => {
+                write!(f, "{}: unknown key `{}`.", source, key)
+            },
+            ConfigError::TypeMismatch { source, key, expected } => {
+                write!(f, "{}: `{}` must be {}.", source, key, expected)
+            },
+            ConfigError::OutOfRange { source, key, value, expected } => {
+                write!(f, "{}: `{}` is {}, but must be {}.", source, key, value, expected)
+            },
+            ConfigError::Missing { key } => {
+                write!(f, "missing key `{}`.", key)
+            },
+        }
+    }
 }